@@ -0,0 +1,55 @@
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn decode_digit(digit: u8) -> Option<u32> {
+    return match digit {
+        b'A'..=b'Z' => Some((digit - b'A') as u32),
+        b'a'..=b'z' => Some((digit - b'a' + 26) as u32),
+        b'0'..=b'9' => Some((digit - b'0' + 52) as u32),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    };
+}
+
+pub fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[((value >> (18 - 6 * index)) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    return encoded;
+}
+
+pub fn decode(encoded: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = encoded
+        .bytes()
+        .filter(|digit| !digit.is_ascii_whitespace())
+        .collect();
+    let digits = match digits.iter().position(|digit| *digit == b'=') {
+        Some(padding) if digits[padding..].iter().all(|digit| *digit == b'=') => &digits[..padding],
+        Some(_) => return None,
+        None => &digits[..],
+    };
+    if digits.len() % 4 == 1 {
+        return None;
+    }
+    let mut decoded: Vec<u8> = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        let mut value: u32 = 0;
+        for (index, digit) in chunk.iter().enumerate() {
+            value |= decode_digit(*digit)? << (18 - 6 * index);
+        }
+        for index in 0..chunk.len() - 1 {
+            decoded.push((value >> (16 - 8 * index)) as u8);
+        }
+    }
+    return Some(decoded);
+}
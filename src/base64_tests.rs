@@ -0,0 +1,33 @@
+use crate::base64::{decode, encode};
+
+#[test]
+fn test_base64_round_trip() -> Result<(), String> {
+    let cases: [(&[u8], &str); 6] = [
+        (b"", ""),
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foobar", "Zm9vYmFy"),
+        ("héllo ✓".as_bytes(), "aMOpbGxvIOKckw=="),
+    ];
+    for (bytes, expected) in cases {
+        if encode(bytes) != expected {
+            Err(format!("{:?}: {} != {}", bytes, encode(bytes), expected))?;
+        }
+        if decode(expected).as_deref() != Some(bytes) {
+            Err(format!("{}: {:?}", expected, decode(expected)))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_base64_rejects_invalid_input() -> Result<(), String> {
+    for encoded in ["Zm9v!", "Z", "Zg==Zg", "Zm 9v\n"] {
+        let expected_valid = encoded == "Zm 9v\n";
+        if decode(encoded).is_some() != expected_valid {
+            Err(format!("{:?}: {:?}", encoded, decode(encoded)))?;
+        }
+    }
+    return Ok(());
+}
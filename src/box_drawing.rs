@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use crate::string_plus::{DecCharSet, StringPlus};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BorderStyle {
+    DecLine,
+    Light,
+    Heavy,
+    Double,
+    Rounded,
+    Ascii,
+}
+
+pub const LINE_UP: u8 = 0b0001;
+pub const LINE_DOWN: u8 = 0b0010;
+pub const LINE_LEFT: u8 = 0b0100;
+pub const LINE_RIGHT: u8 = 0b1000;
+
+const LIGHT: [char; 11] = ['─', '│', '┌', '┐', '└', '┘', '├', '┤', '┬', '┴', '┼'];
+const HEAVY: [char; 11] = ['━', '┃', '┏', '┓', '┗', '┛', '┣', '┫', '┳', '┻', '╋'];
+const DOUBLE: [char; 11] = ['═', '║', '╔', '╗', '╚', '╝', '╠', '╣', '╦', '╩', '╬'];
+const ROUNDED: [char; 11] = ['─', '│', '╭', '╮', '╰', '╯', '├', '┤', '┬', '┴', '┼'];
+const ASCII: [char; 11] = ['-', '|', '+', '+', '+', '+', '+', '+', '+', '+', '+'];
+const DEC_LINE: [DecCharSet; 11] = [
+    DecCharSet::HorizontalBar,
+    DecCharSet::VerticalBar,
+    DecCharSet::TopLeft,
+    DecCharSet::TopRight,
+    DecCharSet::BottomLeft,
+    DecCharSet::BottomRight,
+    DecCharSet::MiddleLeft,
+    DecCharSet::MiddleRight,
+    DecCharSet::TopMiddle,
+    DecCharSet::BottomMiddle,
+    DecCharSet::MiddleMiddle,
+];
+
+const MIXED_GLYPHS: [(char, [u8; 4]); 68] = [
+    ('┍', [0, 1, 0, 2]),
+    ('┎', [0, 2, 0, 1]),
+    ('┑', [0, 1, 2, 0]),
+    ('┒', [0, 2, 1, 0]),
+    ('┕', [1, 0, 0, 2]),
+    ('┖', [2, 0, 0, 1]),
+    ('┙', [1, 0, 2, 0]),
+    ('┚', [2, 0, 1, 0]),
+    ('┝', [1, 1, 0, 2]),
+    ('┞', [2, 1, 0, 1]),
+    ('┟', [1, 2, 0, 1]),
+    ('┠', [2, 2, 0, 1]),
+    ('┡', [2, 1, 0, 2]),
+    ('┢', [1, 2, 0, 2]),
+    ('┥', [1, 1, 2, 0]),
+    ('┦', [2, 1, 1, 0]),
+    ('┧', [1, 2, 1, 0]),
+    ('┨', [2, 2, 1, 0]),
+    ('┩', [2, 1, 2, 0]),
+    ('┪', [1, 2, 2, 0]),
+    ('┭', [0, 1, 2, 1]),
+    ('┮', [0, 1, 1, 2]),
+    ('┯', [0, 1, 2, 2]),
+    ('┰', [0, 2, 1, 1]),
+    ('┱', [0, 2, 2, 1]),
+    ('┲', [0, 2, 1, 2]),
+    ('┵', [1, 0, 2, 1]),
+    ('┶', [1, 0, 1, 2]),
+    ('┷', [1, 0, 2, 2]),
+    ('┸', [2, 0, 1, 1]),
+    ('┹', [2, 0, 2, 1]),
+    ('┺', [2, 0, 1, 2]),
+    ('┽', [1, 1, 2, 1]),
+    ('┾', [1, 1, 1, 2]),
+    ('┿', [1, 1, 2, 2]),
+    ('╀', [2, 1, 1, 1]),
+    ('╁', [1, 2, 1, 1]),
+    ('╂', [2, 2, 1, 1]),
+    ('╃', [2, 1, 2, 1]),
+    ('╄', [2, 1, 1, 2]),
+    ('╅', [1, 2, 2, 1]),
+    ('╆', [1, 2, 1, 2]),
+    ('╇', [2, 1, 2, 2]),
+    ('╈', [1, 2, 2, 2]),
+    ('╉', [2, 2, 2, 1]),
+    ('╊', [2, 2, 1, 2]),
+    ('╒', [0, 1, 0, 3]),
+    ('╓', [0, 3, 0, 1]),
+    ('╕', [0, 1, 3, 0]),
+    ('╖', [0, 3, 1, 0]),
+    ('╘', [1, 0, 0, 3]),
+    ('╙', [3, 0, 0, 1]),
+    ('╛', [1, 0, 3, 0]),
+    ('╜', [3, 0, 1, 0]),
+    ('╞', [1, 1, 0, 3]),
+    ('╟', [3, 3, 0, 1]),
+    ('╡', [1, 1, 3, 0]),
+    ('╢', [3, 3, 1, 0]),
+    ('╤', [0, 1, 3, 3]),
+    ('╥', [0, 3, 1, 1]),
+    ('╧', [1, 0, 3, 3]),
+    ('╨', [3, 0, 1, 1]),
+    ('╪', [1, 1, 3, 3]),
+    ('╫', [3, 3, 1, 1]),
+    ('╼', [0, 0, 1, 2]),
+    ('╽', [1, 2, 0, 0]),
+    ('╾', [0, 0, 2, 1]),
+    ('╿', [2, 1, 0, 0]),
+];
+
+fn get_glyph_index(mask: u8) -> usize {
+    const VERTICAL: u8 = LINE_UP | LINE_DOWN;
+    const HORIZONTAL: u8 = LINE_LEFT | LINE_RIGHT;
+    return match mask & 0b1111 {
+        0 | LINE_LEFT | LINE_RIGHT | HORIZONTAL => 0,
+        LINE_UP | LINE_DOWN | VERTICAL => 1,
+        0b1010 => 2,
+        0b0110 => 3,
+        0b1001 => 4,
+        0b0101 => 5,
+        0b1011 => 6,
+        0b0111 => 7,
+        0b1110 => 8,
+        0b1101 => 9,
+        _ => 10,
+    };
+}
+
+pub fn get_line_glyph(border_style: BorderStyle, mask: u8) -> StringPlus {
+    let index = get_glyph_index(mask);
+    let glyphs = match border_style {
+        BorderStyle::DecLine => return DEC_LINE[index].into(),
+        BorderStyle::Light => LIGHT,
+        BorderStyle::Heavy => HEAVY,
+        BorderStyle::Double => DOUBLE,
+        BorderStyle::Rounded => ROUNDED,
+        BorderStyle::Ascii => ASCII,
+    };
+    return glyphs[index].into();
+}
+
+fn get_weight(border_style: Option<BorderStyle>) -> u8 {
+    return match border_style {
+        None => 0,
+        Some(BorderStyle::Light | BorderStyle::Rounded) => 1,
+        Some(BorderStyle::Heavy) => 2,
+        Some(BorderStyle::Double) => 3,
+        Some(BorderStyle::DecLine | BorderStyle::Ascii) => 4,
+    };
+}
+
+fn get_cell_glyph(border_style: BorderStyle, directions: [Option<BorderStyle>; 4]) -> StringPlus {
+    let mask = (0..4)
+        .filter(|&direction| directions[direction].is_some())
+        .fold(0, |mask, direction| mask | 1 << direction);
+    let weights = directions.map(get_weight);
+    return match MIXED_GLYPHS
+        .iter()
+        .find(|(_, glyph_weights)| *glyph_weights == weights)
+    {
+        Some((glyph, _)) => (*glyph).into(),
+        None => get_line_glyph(border_style, mask),
+    };
+}
+
+type LineCell = (BorderStyle, [Option<BorderStyle>; 4]);
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LineCells {
+    cells: HashMap<(u16, u16), LineCell>,
+    size: Option<(u16, u16)>,
+}
+
+impl LineCells {
+    pub(crate) fn merge(
+        &mut self,
+        cells: Vec<((u16, u16), u8)>,
+        border_style: BorderStyle,
+    ) -> Vec<((u16, u16), StringPlus)> {
+        let mut merged_cells: Vec<(u16, u16)> = Vec::new();
+        for (position, mask) in cells {
+            let cell = self
+                .cells
+                .entry(position)
+                .or_insert((border_style, [None; 4]));
+            cell.0 = border_style;
+            for direction in 0..4 {
+                if mask & 1 << direction != 0 {
+                    cell.1[direction] = Some(border_style);
+                }
+            }
+            if !merged_cells.contains(&position) {
+                merged_cells.push(position);
+            }
+        }
+        return merged_cells
+            .into_iter()
+            .map(|position| {
+                let (border_style, directions) = self.cells[&position];
+                (position, get_cell_glyph(border_style, directions))
+            })
+            .collect();
+    }
+
+    pub(crate) fn forget(&mut self, x: u16, y: u16, length: u16) {
+        for offset in 0..length {
+            self.cells.remove(&(x.saturating_add(offset), y));
+        }
+    }
+
+    pub(crate) fn set_size(&mut self, size: (u16, u16)) {
+        if self.size != Some(size) {
+            self.cells.clear();
+            self.size = Some(size);
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.cells.clear();
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{
+    box_drawing::{
+        get_line_glyph, BorderStyle, LineCells, LINE_DOWN, LINE_LEFT, LINE_RIGHT, LINE_UP,
+    },
+    rect::Rect,
+    tui_terminal::TuiTerminal,
+};
+
+#[test]
+fn test_line_glyphs() -> Result<(), String> {
+    let cases = [
+        (BorderStyle::Light, LINE_RIGHT | LINE_DOWN, "┌"),
+        (BorderStyle::Rounded, LINE_LEFT | LINE_UP, "╯"),
+        (BorderStyle::Double, LINE_UP | LINE_DOWN | LINE_RIGHT, "╠"),
+        (BorderStyle::Heavy, LINE_LEFT | LINE_RIGHT | LINE_DOWN, "┳"),
+        (
+            BorderStyle::Light,
+            LINE_UP | LINE_DOWN | LINE_LEFT | LINE_RIGHT,
+            "┼",
+        ),
+        (BorderStyle::Ascii, LINE_LEFT, "-"),
+    ];
+    for (border_style, mask, expected) in cases {
+        let glyph = get_line_glyph(border_style, mask).to_string();
+        if glyph != expected {
+            Err(format!(
+                "{:?} {:04b}: {} != {}",
+                border_style, mask, glyph, expected
+            ))?;
+        }
+    }
+    let dec_glyph = get_line_glyph(BorderStyle::DecLine, LINE_RIGHT | LINE_DOWN);
+    if !dec_glyph.get_dec_line() || dec_glyph.to_string() != "l" {
+        Err(format!("{:?}", dec_glyph))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_rect_geometry() -> Result<(), String> {
+    let rect = Rect::new(2, 3, 10, 4);
+    let inner = rect.inner(1);
+    let intersection = rect.intersection(Rect::new(8, 1, 10, 4));
+    if inner != Rect::new(3, 4, 8, 2)
+        || intersection != Rect::new(8, 3, 4, 2)
+        || !rect.contains(11, 6)
+        || rect.contains(12, 6)
+    {
+        Err(format!("{:?} {:?}", inner, intersection))?;
+    }
+    return Ok(());
+}
+
+fn get_glyphs(
+    line_cells: &mut LineCells,
+    cells: Vec<((u16, u16), u8)>,
+    border_style: BorderStyle,
+) -> Vec<String> {
+    return line_cells
+        .merge(cells, border_style)
+        .into_iter()
+        .map(|(_, glyph)| glyph.to_string())
+        .collect();
+}
+
+#[test]
+fn test_line_cells_junctions() -> Result<(), String> {
+    let mut line_cells = LineCells::default();
+    let horizontal = TuiTerminal::get_horizontal_line_cells(1, 2, 3);
+    let glyphs = get_glyphs(&mut line_cells, horizontal, BorderStyle::Light);
+    if glyphs != ["─", "─", "─"] {
+        Err(format!("{:?}", glyphs))?;
+    }
+    let vertical = TuiTerminal::get_vertical_line_cells(2, 1, 3);
+    let glyphs = get_glyphs(&mut line_cells, vertical, BorderStyle::Light);
+    if glyphs != ["│", "┼", "│"] {
+        Err(format!("{:?}", glyphs))?;
+    }
+    let vertical = TuiTerminal::get_vertical_line_cells(3, 2, 2);
+    let glyphs = get_glyphs(&mut line_cells, vertical, BorderStyle::Double);
+    if glyphs != ["╖", "║"] {
+        Err(format!("{:?}", glyphs))?;
+    }
+    let vertical = TuiTerminal::get_vertical_line_cells(3, 1, 2);
+    let glyphs = get_glyphs(&mut line_cells, vertical, BorderStyle::Double);
+    if glyphs != ["║", "╢"] {
+        Err(format!("{:?}", glyphs))?;
+    }
+    line_cells.forget(2, 2, 1);
+    let vertical = TuiTerminal::get_vertical_line_cells(2, 2, 2);
+    let glyphs = get_glyphs(&mut line_cells, vertical, BorderStyle::Light);
+    if glyphs != ["│", "│"] {
+        Err(format!("{:?}", glyphs))?;
+    }
+    line_cells.set_size((80, 24));
+    line_cells.set_size((100, 30));
+    let horizontal = TuiTerminal::get_horizontal_line_cells(1, 3, 3);
+    let glyphs = get_glyphs(&mut line_cells, horizontal, BorderStyle::Light);
+    if glyphs != ["─", "─", "─"] {
+        Err(format!("{:?}", glyphs))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_line_cells_mixed_junctions() -> Result<(), String> {
+    let cases = [
+        (BorderStyle::Light, BorderStyle::Heavy, ["╂", "┠", "┨"]),
+        (BorderStyle::Heavy, BorderStyle::Light, ["┿", "┝", "┥"]),
+        (BorderStyle::Double, BorderStyle::Light, ["╪", "╞", "╡"]),
+        (BorderStyle::Light, BorderStyle::Double, ["╫", "╟", "╢"]),
+        (BorderStyle::Double, BorderStyle::Heavy, ["╋", "┣", "┫"]),
+        (BorderStyle::Rounded, BorderStyle::Light, ["┼", "├", "┤"]),
+    ];
+    for (horizontal_style, vertical_style, expected) in cases {
+        let mut line_cells = LineCells::default();
+        let horizontal = TuiTerminal::get_horizontal_line_cells(1, 2, 3);
+        get_glyphs(&mut line_cells, horizontal, horizontal_style);
+        let mut glyphs: Vec<String> = Vec::new();
+        for x in [2, 1, 3] {
+            let vertical = TuiTerminal::get_vertical_line_cells(x, 1, 3);
+            glyphs.push(get_glyphs(&mut line_cells, vertical, vertical_style)[1].clone());
+        }
+        if glyphs != expected {
+            Err(format!(
+                "{:?} {:?}: {:?} != {:?}",
+                horizontal_style, vertical_style, glyphs, expected
+            ))?;
+        }
+    }
+    return Ok(());
+}
@@ -0,0 +1,340 @@
+use crate::{
+    string_plus::{StringPlus, StringPlusTrait},
+    tui_enums::Color,
+    widget::merge_cells,
+};
+
+const BRAILLE_BASE: u32 = 0x2800;
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+const CLIP_LEFT: u8 = 0b0001;
+const CLIP_RIGHT: u8 = 0b0010;
+const CLIP_TOP: u8 = 0b0100;
+const CLIP_BOTTOM: u8 = 0b1000;
+
+type LineSegment = (i64, i64, i64, i64);
+
+fn get_clip_code(x: i64, y: i64, right: i64, bottom: i64) -> u8 {
+    let mut code = 0;
+    if x < 0 {
+        code |= CLIP_LEFT;
+    } else if x > right {
+        code |= CLIP_RIGHT;
+    }
+    if y < 0 {
+        code |= CLIP_TOP;
+    } else if y > bottom {
+        code |= CLIP_BOTTOM;
+    }
+    return code;
+}
+
+fn interpolate(start: i64, end: i64, numerator: i64, denominator: i64) -> i64 {
+    let offset = (end - start) as i128 * numerator as i128 / denominator as i128;
+    return start + offset as i64;
+}
+
+pub(crate) fn clip_line(line: LineSegment, width: u32, height: u32) -> Option<LineSegment> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let (right, bottom) = (width as i64 - 1, height as i64 - 1);
+    let (mut x0, mut y0, mut x1, mut y1) = line;
+    let mut code0 = get_clip_code(x0, y0, right, bottom);
+    let mut code1 = get_clip_code(x1, y1, right, bottom);
+    loop {
+        if code0 | code1 == 0 {
+            return Some((x0, y0, x1, y1));
+        }
+        if code0 & code1 != 0 {
+            return None;
+        }
+        let code = match code0 {
+            0 => code1,
+            _ => code0,
+        };
+        let (x, y) = if code & CLIP_BOTTOM != 0 {
+            (interpolate(x0, x1, bottom - y0, y1 - y0), bottom)
+        } else if code & CLIP_TOP != 0 {
+            (interpolate(x0, x1, -y0, y1 - y0), 0)
+        } else if code & CLIP_RIGHT != 0 {
+            (right, interpolate(y0, y1, right - x0, x1 - x0))
+        } else {
+            (0, interpolate(y0, y1, -x0, x1 - x0))
+        };
+        if code == code0 {
+            (x0, y0) = (x, y);
+            code0 = get_clip_code(x0, y0, right, bottom);
+        } else {
+            (x1, y1) = (x, y);
+            code1 = get_clip_code(x1, y1, right, bottom);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CanvasMarker {
+    HalfBlock,
+    Braille,
+}
+
+impl CanvasMarker {
+    pub fn get_cell_size(&self) -> (u16, u16) {
+        return match self {
+            CanvasMarker::HalfBlock => (1, 2),
+            CanvasMarker::Braille => (2, 4),
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Canvas {
+    marker: CanvasMarker,
+    columns: u16,
+    rows: u16,
+    pixels: Vec<Option<Color>>,
+    cell_colors: Vec<Option<Color>>,
+    cell_backgrounds: Vec<Color>,
+}
+
+impl Canvas {
+    pub fn new(columns: u16, rows: u16, marker: CanvasMarker) -> Canvas {
+        let (cell_width, cell_height) = marker.get_cell_size();
+        let cells = columns as usize * rows as usize;
+        return Canvas {
+            marker,
+            columns,
+            rows,
+            pixels: vec![None; cells * cell_width as usize * cell_height as usize],
+            cell_colors: vec![None; cells],
+            cell_backgrounds: vec![Color::Default; cells],
+        };
+    }
+
+    pub fn get_marker(&self) -> CanvasMarker {
+        return self.marker;
+    }
+
+    pub fn get_columns(&self) -> u16 {
+        return self.columns;
+    }
+
+    pub fn get_rows(&self) -> u16 {
+        return self.rows;
+    }
+
+    pub fn get_width(&self) -> u32 {
+        return self.columns as u32 * self.marker.get_cell_size().0 as u32;
+    }
+
+    pub fn get_height(&self) -> u32 {
+        return self.rows as u32 * self.marker.get_cell_size().1 as u32;
+    }
+
+    pub fn clear(&mut self) {
+        self.pixels.fill(None);
+        self.cell_colors.fill(None);
+        self.cell_backgrounds.fill(Color::Default);
+    }
+
+    fn get_pixel_index(&self, x: i64, y: i64) -> Option<usize> {
+        if x < 0 || y < 0 || x >= self.get_width() as i64 || y >= self.get_height() as i64 {
+            return None;
+        }
+        return Some(y as usize * self.get_width() as usize + x as usize);
+    }
+
+    fn get_cell_index(&self, x: i64, y: i64) -> usize {
+        let (cell_width, cell_height) = self.marker.get_cell_size();
+        return (y as usize / cell_height as usize) * self.columns as usize
+            + x as usize / cell_width as usize;
+    }
+
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<Color> {
+        return self.get_point(x as i64, y as i64);
+    }
+
+    fn get_point(&self, x: i64, y: i64) -> Option<Color> {
+        return self.pixels[self.get_pixel_index(x, y)?];
+    }
+
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: Color) {
+        self.set_point(x as i64, y as i64, color);
+    }
+
+    fn set_point(&mut self, x: i64, y: i64, color: Color) {
+        let Some(index) = self.get_pixel_index(x, y) else {
+            return;
+        };
+        self.pixels[index] = Some(color);
+        let cell_index = self.get_cell_index(x, y);
+        self.cell_colors[cell_index] = Some(color);
+    }
+
+    pub fn unset_pixel(&mut self, x: i32, y: i32) {
+        if let Some(index) = self.get_pixel_index(x as i64, y as i64) {
+            self.pixels[index] = None;
+        }
+    }
+
+    pub fn set_cell_background(&mut self, column: u16, row: u16, color: Color) {
+        if column < self.columns && row < self.rows {
+            self.cell_backgrounds[row as usize * self.columns as usize + column as usize] = color;
+        }
+    }
+
+    pub fn draw_line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, color: Color) {
+        self.draw_segment((x0 as i64, y0 as i64, x1 as i64, y1 as i64), color);
+    }
+
+    fn draw_segment(&mut self, line: LineSegment, color: Color) {
+        let Some((x0, y0, x1, y1)) = clip_line(line, self.get_width(), self.get_height()) else {
+            return;
+        };
+        let (delta_x, delta_y) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (step_x, step_y) = ((x1 - x0).signum(), (y1 - y0).signum());
+        let (mut x, mut y) = (x0, y0);
+        let mut error = delta_x + delta_y;
+        loop {
+            self.set_point(x, y, color);
+            if x == x1 && y == y1 {
+                return;
+            }
+            let doubled_error = 2 * error;
+            if doubled_error >= delta_y {
+                error += delta_y;
+                x += step_x;
+            }
+            if doubled_error <= delta_x {
+                error += delta_x;
+                y += step_y;
+            }
+        }
+    }
+
+    pub fn draw_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+        let (x, y) = (x as i64, y as i64);
+        let (right, bottom) = (x + width as i64 - 1, y + height as i64 - 1);
+        self.draw_segment((x, y, right, y), color);
+        self.draw_segment((x, bottom, right, bottom), color);
+        self.draw_segment((x, y, x, bottom), color);
+        self.draw_segment((right, y, right, bottom), color);
+    }
+
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        let (left, top) = ((x as i64).max(0), (y as i64).max(0));
+        let right = (x as i64 + width as i64).min(self.get_width() as i64);
+        let bottom = (y as i64 + height as i64).min(self.get_height() as i64);
+        for row in top..bottom {
+            for column in left..right {
+                self.set_point(column, row, color);
+            }
+        }
+    }
+
+    fn draw_circle_points(&mut self, center_x: i64, center_y: i64, x: i64, y: i64, color: Color) {
+        for (offset_x, offset_y) in [(x, y), (y, x)] {
+            for (sign_x, sign_y) in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
+                self.set_point(
+                    center_x + sign_x * offset_x,
+                    center_y + sign_y * offset_y,
+                    color,
+                );
+            }
+        }
+    }
+
+    pub fn draw_circle(&mut self, center_x: i32, center_y: i32, radius: i32, color: Color) {
+        if radius < 0 {
+            return;
+        }
+        let (center_x, center_y, radius) = (center_x as i64, center_y as i64, radius as i64);
+        let (mut x, mut y) = (radius, 0);
+        let mut error = 1 - radius;
+        while x >= y {
+            self.draw_circle_points(center_x, center_y, x, y, color);
+            y += 1;
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    pub fn fill_circle(&mut self, center_x: i32, center_y: i32, radius: i32, color: Color) {
+        let (center_x, center_y, radius) = (center_x as i64, center_y as i64, radius as i64);
+        let (left, top) = ((center_x - radius).max(0), (center_y - radius).max(0));
+        let right = (center_x + radius).min(self.get_width() as i64 - 1);
+        let bottom = (center_y + radius).min(self.get_height() as i64 - 1);
+        for y in top..=bottom {
+            for x in left..=right {
+                let (offset_x, offset_y) = (x - center_x, y - center_y);
+                if offset_x * offset_x + offset_y * offset_y <= radius * radius {
+                    self.set_point(x, y, color);
+                }
+            }
+        }
+    }
+
+    pub fn get_cell(&self, column: u16, row: u16) -> StringPlus {
+        let cell_index = row as usize * self.columns as usize + column as usize;
+        let background = self.cell_backgrounds[cell_index];
+        let (cell_width, cell_height) = self.marker.get_cell_size();
+        let (x, y) = (
+            column as i64 * cell_width as i64,
+            row as i64 * cell_height as i64,
+        );
+        return match self.marker {
+            CanvasMarker::HalfBlock => match (self.get_point(x, y), self.get_point(x, y + 1)) {
+                (Some(top), Some(bottom)) => {
+                    "\u{2580}".set_font_color(top).set_background_color(bottom)
+                }
+                (Some(top), None) => "\u{2580}"
+                    .set_font_color(top)
+                    .set_background_color(background),
+                (None, Some(bottom)) => "\u{2584}"
+                    .set_font_color(bottom)
+                    .set_background_color(background),
+                (None, None) => " ".set_background_color(background),
+            },
+            CanvasMarker::Braille => {
+                let mut dots: u32 = 0;
+                for (offset_y, row_dots) in BRAILLE_DOTS.iter().enumerate() {
+                    for (offset_x, dot) in row_dots.iter().enumerate() {
+                        if self
+                            .get_point(x + offset_x as i64, y + offset_y as i64)
+                            .is_some()
+                        {
+                            dots |= *dot as u32;
+                        }
+                    }
+                }
+                let color = match dots {
+                    0 => Color::Default,
+                    _ => self.cell_colors[cell_index].unwrap_or(Color::Default),
+                };
+                char::from_u32(BRAILLE_BASE + dots)
+                    .unwrap_or(' ')
+                    .set_font_color(color)
+                    .set_background_color(background)
+            }
+        };
+    }
+
+    pub fn to_lines(&self) -> Vec<Vec<StringPlus>> {
+        return (0..self.rows)
+            .map(|row| {
+                merge_cells(
+                    (0..self.columns)
+                        .map(|column| self.get_cell(column, row))
+                        .collect(),
+                )
+            })
+            .collect();
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    canvas::{clip_line, Canvas, CanvasMarker},
+    tui_enums::Color,
+};
+
+fn line_text(canvas: &Canvas, row: usize) -> String {
+    return canvas.to_lines()[row]
+        .iter()
+        .map(|span| span.to_string())
+        .collect();
+}
+
+#[test]
+fn test_braille_line() -> Result<(), String> {
+    let mut canvas = Canvas::new(2, 1, CanvasMarker::Braille);
+    canvas.draw_line(0, 0, 3, 3, Color::Red);
+    let text = line_text(&canvas, 0);
+    if text != "\u{2811}\u{2884}" {
+        Err(format!("{:?}", text))?;
+    }
+    if canvas.to_lines()[0][0].get_font_color() != Color::Red {
+        Err("Braille cell lost its color")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_half_block_colors() -> Result<(), String> {
+    let mut canvas = Canvas::new(3, 1, CanvasMarker::HalfBlock);
+    canvas.set_pixel(0, 0, Color::Red);
+    canvas.set_pixel(0, 1, Color::Blue);
+    canvas.set_pixel(1, 1, Color::Green);
+    canvas.set_pixel(5, 5, Color::Green);
+    let line = &canvas.to_lines()[0];
+    let text = line_text(&canvas, 0);
+    if text != "\u{2580}\u{2584} " {
+        Err(format!("{:?}", text))?;
+    }
+    if line[0].get_font_color() != Color::Red || line[0].get_background_color() != Color::Blue {
+        Err(format!("{:?}", line[0]))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_shapes() -> Result<(), String> {
+    let mut canvas = Canvas::new(10, 5, CanvasMarker::Braille);
+    canvas.draw_circle(10, 10, 5, Color::Default);
+    for (x, y) in [(15, 10), (5, 10), (10, 15), (10, 5)] {
+        if canvas.get_pixel(x, y).is_none() {
+            Err(format!("Circle missing ({}, {})", x, y))?;
+        }
+    }
+    if canvas.get_pixel(10, 10).is_some() {
+        Err("Circle outline filled its center")?;
+    }
+    canvas.clear();
+    canvas.draw_rect(2, 2, 4, 3, Color::Default);
+    let lit = (0..20)
+        .flat_map(|y| (0..20).map(move |x| (x, y)))
+        .filter(|(x, y)| canvas.get_pixel(*x, *y).is_some())
+        .count();
+    if lit != 10 {
+        Err(format!("Rectangle outline has {} pixels", lit))?;
+    }
+    return Ok(());
+}
+
+fn lit_pixels(canvas: &Canvas) -> Vec<(i32, i32)> {
+    return (0..canvas.get_height() as i32)
+        .flat_map(|y| (0..canvas.get_width() as i32).map(move |x| (x, y)))
+        .filter(|(x, y)| canvas.get_pixel(*x, *y).is_some())
+        .collect();
+}
+
+#[test]
+fn test_clip_line() -> Result<(), String> {
+    let cases = [
+        ((1, 1, 5, 3), Some((1, 1, 5, 3))),
+        ((-10, 2, 20, 2), Some((0, 2, 9, 2))),
+        ((4, -6, 4, 100), Some((4, 0, 4, 4))),
+        ((-5, -5, 15, 5), Some((5, 0, 9, 2))),
+        ((-5, 0, -1, 4), None),
+        ((0, 10, 9, 10), None),
+        ((0, 0, i32::MAX as i64, i32::MIN as i64), Some((0, 0, 0, 0))),
+    ];
+    for (line, expected) in cases {
+        let clipped = clip_line(line, 10, 5);
+        if clipped != expected {
+            Err(format!("{:?}: {:?} != {:?}", line, clipped, expected))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_shapes_outside_canvas() -> Result<(), String> {
+    let mut canvas = Canvas::new(4, 2, CanvasMarker::Braille);
+    canvas.draw_line(i32::MIN, 3, i32::MAX, 3, Color::Red);
+    let lit = lit_pixels(&canvas);
+    if lit != (0..8).map(|x| (x, 3)).collect::<Vec<_>>() {
+        Err(format!("Clipped line: {:?}", lit))?;
+    }
+    canvas.clear();
+    canvas.draw_line(
+        -1_000_000_000,
+        -1_000_000_000,
+        i32::MAX,
+        i32::MAX,
+        Color::Red,
+    );
+    if lit_pixels(&canvas) != (0..8).map(|x| (x, x)).collect::<Vec<_>>() {
+        Err(format!("Clipped diagonal: {:?}", lit_pixels(&canvas)))?;
+    }
+    canvas.clear();
+    canvas.fill_rect(i32::MIN, i32::MIN, i32::MAX, i32::MAX, Color::Red);
+    canvas.fill_rect(6, 6, i32::MAX, i32::MAX, Color::Red);
+    canvas.draw_rect(6, 6, i32::MAX, i32::MAX, Color::Red);
+    if lit_pixels(&canvas) != vec![(6, 6), (7, 6), (6, 7), (7, 7)] {
+        Err(format!("Clipped rects: {:?}", lit_pixels(&canvas)))?;
+    }
+    canvas.clear();
+    canvas.fill_circle(i32::MAX, i32::MAX, i32::MAX, Color::Red);
+    canvas.fill_circle(i32::MIN, 0, 10, Color::Red);
+    canvas.draw_circle(i32::MAX, i32::MIN, 3, Color::Red);
+    if !lit_pixels(&canvas).is_empty() {
+        Err(format!("Clipped circles: {:?}", lit_pixels(&canvas)))?;
+    }
+    canvas.fill_circle(0, 0, i32::MAX, Color::Red);
+    if lit_pixels(&canvas).len() != 64 {
+        Err(format!("Huge circle: {:?}", lit_pixels(&canvas)))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_large_canvas_cells() -> Result<(), String> {
+    let mut canvas = Canvas::new(1, 16385, CanvasMarker::Braille);
+    canvas.set_pixel(1, 16384 * 4 + 3, Color::Red);
+    let lines = canvas.to_lines();
+    let last = lines[16384]
+        .iter()
+        .map(|span| span.to_string())
+        .collect::<String>();
+    if lines.len() != 16385 || last != "\u{2880}" {
+        Err(format!("{} {:?}", lines.len(), last))?;
+    }
+    let canvas = Canvas::new(32769, 1, CanvasMarker::Braille);
+    if canvas.get_cell(32768, 0).to_string() != "\u{2800}" {
+        Err("Wide canvas cell")?;
+    }
+    let canvas = Canvas::new(1, 40000, CanvasMarker::HalfBlock);
+    if canvas.get_cell(0, 39999).to_string() != " " {
+        Err("Tall half block canvas cell")?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,483 @@
+use crate::{
+    canvas::{Canvas, CanvasMarker},
+    layout::Direction,
+    string_plus::{StringPlus, StringPlusTrait},
+    theme::ThemeRole,
+    tui_enums::{Alignment, Color},
+    widget::{merge_cells, Widget},
+};
+
+const LOWER_EIGHTHS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const LEFT_EIGHTHS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+pub fn format_value(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return (value as i64).to_string();
+    }
+    let formatted = format!("{:.2}", value);
+    return formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string();
+}
+
+fn get_fraction(value: f64, max: f64) -> f64 {
+    if max <= 0.0 || !value.is_finite() {
+        return 0.0;
+    }
+    return (value / max).clamp(0.0, 1.0);
+}
+
+fn get_eighths_char(eighths: &[char; 8], level: usize) -> char {
+    return match level {
+        0 => ' ',
+        _ => eighths[level.min(8) - 1],
+    };
+}
+
+fn get_value_color(color: Color, gradient: Option<(Color, Color)>, fraction: f64) -> Color {
+    return match gradient {
+        Some((start, end)) => start.lerp(end, fraction as f32),
+        None => color,
+    };
+}
+
+fn get_max(values: impl Iterator<Item = f64>, max: Option<f64>) -> f64 {
+    return max.unwrap_or_else(|| values.filter(|value| value.is_finite()).fold(0.0, f64::max));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sparkline {
+    data: Vec<f64>,
+    max: Option<f64>,
+    color: Color,
+    gradient: Option<(Color, Color)>,
+}
+
+impl Sparkline {
+    pub fn new(data: Vec<f64>) -> Sparkline {
+        return Sparkline {
+            data,
+            max: None,
+            color: Color::Default,
+            gradient: None,
+        };
+    }
+
+    pub fn max(mut self, max: f64) -> Sparkline {
+        self.max = Some(max);
+        return self;
+    }
+
+    pub fn color(mut self, color: Color) -> Sparkline {
+        self.color = color;
+        return self;
+    }
+
+    pub fn gradient(mut self, start: Color, end: Color) -> Sparkline {
+        self.gradient = Some((start, end));
+        return self;
+    }
+}
+
+impl Widget for Sparkline {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>> {
+        let data = &self.data[self.data.len().saturating_sub(width as usize)..];
+        let max = get_max(data.iter().copied(), self.max);
+        let height = height as usize;
+        return (0..height)
+            .map(|row| {
+                let floor = (height - 1 - row) * 8;
+                merge_cells(
+                    data.iter()
+                        .map(|value| {
+                            let fraction = get_fraction(*value, max);
+                            let level = (fraction * (height * 8) as f64).round() as usize;
+                            get_eighths_char(&LOWER_EIGHTHS, level.saturating_sub(floor))
+                                .set_font_color(get_value_color(
+                                    self.color,
+                                    self.gradient,
+                                    fraction,
+                                ))
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarChart {
+    bars: Vec<(String, f64)>,
+    direction: Direction,
+    bar_width: u16,
+    gap: u16,
+    max: Option<f64>,
+    color: Color,
+    gradient: Option<(Color, Color)>,
+    show_values: bool,
+}
+
+impl BarChart {
+    pub fn new(bars: Vec<(String, f64)>) -> BarChart {
+        return BarChart {
+            bars,
+            direction: Direction::Horizontal,
+            bar_width: 3,
+            gap: 1,
+            max: None,
+            color: Color::Default,
+            gradient: None,
+            show_values: true,
+        };
+    }
+
+    pub fn direction(mut self, direction: Direction) -> BarChart {
+        self.direction = direction;
+        return self;
+    }
+
+    pub fn bar_width(mut self, bar_width: u16) -> BarChart {
+        self.bar_width = bar_width.max(1);
+        return self;
+    }
+
+    pub fn gap(mut self, gap: u16) -> BarChart {
+        self.gap = gap;
+        return self;
+    }
+
+    pub fn max(mut self, max: f64) -> BarChart {
+        self.max = Some(max);
+        return self;
+    }
+
+    pub fn color(mut self, color: Color) -> BarChart {
+        self.color = color;
+        return self;
+    }
+
+    pub fn gradient(mut self, start: Color, end: Color) -> BarChart {
+        self.gradient = Some((start, end));
+        return self;
+    }
+
+    pub fn show_values(mut self, show_values: bool) -> BarChart {
+        self.show_values = show_values;
+        return self;
+    }
+
+    fn render_horizontal(&self, width: u16, height: u16, max: f64) -> Vec<Vec<StringPlus>> {
+        let width = width as usize;
+        let label_width = self
+            .bars
+            .iter()
+            .map(|(label, _)| StringPlus::from(label.as_str()).width())
+            .max()
+            .unwrap_or(0)
+            .min(width / 3);
+        let values: Vec<String> = self
+            .bars
+            .iter()
+            .map(|(_, value)| format_value(*value))
+            .collect();
+        let value_width = match self.show_values {
+            true => values
+                .iter()
+                .map(|value| value.len() + 1)
+                .max()
+                .unwrap_or(0),
+            false => 0,
+        };
+        let label_gap = (label_width > 0) as usize;
+        let bar_area = width.saturating_sub(label_width + label_gap + value_width);
+        let mut lines: Vec<Vec<StringPlus>> = Vec::new();
+        for ((label, value), value_text) in self.bars.iter().zip(&values).take(height as usize) {
+            let fraction = get_fraction(*value, max);
+            let eighths = (fraction * (bar_area * 8) as f64).round() as usize;
+            let mut bar: String = "█".repeat(eighths / 8);
+            let partial = eighths % 8;
+            if partial != 0 {
+                bar.push(get_eighths_char(&LEFT_EIGHTHS, partial));
+            }
+            let bar_cells = bar.chars().count();
+            let mut line: Vec<StringPlus> = Vec::new();
+            if label_width > 0 {
+                line.push(StringPlus::from(label.as_str()).align(label_width, Alignment::Left));
+                line.push(" ".into());
+            }
+            line.push(bar.set_font_color(get_value_color(self.color, self.gradient, fraction)));
+            if self.show_values {
+                line.push(" ".repeat(bar_area - bar_cells + 1).into());
+                line.push(value_text.as_str().set_role(ThemeRole::Muted));
+            }
+            lines.push(line);
+        }
+        return lines;
+    }
+
+    fn render_vertical(&self, width: u16, height: u16, max: f64) -> Vec<Vec<StringPlus>> {
+        let bar_width = self.bar_width as usize;
+        let has_labels = self.bars.iter().any(|(label, _)| !label.is_empty());
+        let reserved_rows = has_labels as usize + self.show_values as usize;
+        let bar_height = (height as usize).saturating_sub(reserved_rows);
+        let count = (width as usize + self.gap as usize) / (bar_width + self.gap as usize);
+        let bars = &self.bars[..count.min(self.bars.len())];
+        let gap: String = " ".repeat(self.gap as usize);
+        let text_row = |texts: Vec<StringPlus>| -> Vec<StringPlus> {
+            let mut line: Vec<StringPlus> = Vec::new();
+            for (index, text) in texts.into_iter().enumerate() {
+                if index != 0 {
+                    line.push(gap.as_str().into());
+                }
+                line.push(text.align(bar_width, Alignment::Center));
+            }
+            return line;
+        };
+        let mut lines: Vec<Vec<StringPlus>> = Vec::new();
+        if self.show_values {
+            lines.push(text_row(
+                bars.iter()
+                    .map(|(_, value)| format_value(*value).set_role(ThemeRole::Muted))
+                    .collect(),
+            ));
+        }
+        for row in 0..bar_height {
+            let floor = (bar_height - 1 - row) * 8;
+            let mut line: Vec<StringPlus> = Vec::new();
+            for (index, (_, value)) in bars.iter().enumerate() {
+                if index != 0 {
+                    line.push(gap.as_str().into());
+                }
+                let fraction = get_fraction(*value, max);
+                let level = (fraction * (bar_height * 8) as f64).round() as usize;
+                let cell = get_eighths_char(&LOWER_EIGHTHS, level.saturating_sub(floor));
+                line.push(
+                    cell.to_string()
+                        .repeat(bar_width)
+                        .set_font_color(get_value_color(self.color, self.gradient, fraction)),
+                );
+            }
+            lines.push(line);
+        }
+        if has_labels {
+            lines.push(text_row(
+                bars.iter()
+                    .map(|(label, _)| StringPlus::from(label.as_str()))
+                    .collect(),
+            ));
+        }
+        return lines;
+    }
+}
+
+impl Widget for BarChart {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>> {
+        let max = get_max(self.bars.iter().map(|(_, value)| *value), self.max);
+        return match self.direction {
+            Direction::Horizontal => self.render_horizontal(width, height, max),
+            Direction::Vertical => self.render_vertical(width, height, max),
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Series {
+    name: String,
+    points: Vec<(f64, f64)>,
+    color: Color,
+}
+
+impl Series {
+    pub fn new(name: &str, points: Vec<(f64, f64)>, color: Color) -> Series {
+        return Series {
+            name: name.to_string(),
+            points,
+            color,
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineChart {
+    series: Vec<Series>,
+    x_bounds: Option<(f64, f64)>,
+    y_bounds: Option<(f64, f64)>,
+    show_legend: bool,
+}
+
+fn get_bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if min > max {
+        return (0.0, 1.0);
+    }
+    if min == max {
+        return (min - 1.0, max + 1.0);
+    }
+    return (min, max);
+}
+
+fn scale(value: f64, (min, max): (f64, f64), size: u32) -> i32 {
+    let scaled = ((value - min) / (max - min) * (size.saturating_sub(1)) as f64).round();
+    return scaled.clamp(i32::MIN as f64 / 2.0, i32::MAX as f64 / 2.0) as i32;
+}
+
+impl LineChart {
+    pub fn new(series: Vec<Series>) -> LineChart {
+        return LineChart {
+            series,
+            x_bounds: None,
+            y_bounds: None,
+            show_legend: true,
+        };
+    }
+
+    pub fn x_bounds(mut self, min: f64, max: f64) -> LineChart {
+        self.x_bounds = Some((min, max));
+        return self;
+    }
+
+    pub fn y_bounds(mut self, min: f64, max: f64) -> LineChart {
+        self.y_bounds = Some((min, max));
+        return self;
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> LineChart {
+        self.show_legend = show_legend;
+        return self;
+    }
+
+    fn get_x_bounds(&self) -> (f64, f64) {
+        return self.x_bounds.unwrap_or_else(|| {
+            get_bounds(
+                self.series
+                    .iter()
+                    .flat_map(|series| series.points.iter().map(|(x, _)| *x)),
+            )
+        });
+    }
+
+    fn get_y_bounds(&self) -> (f64, f64) {
+        return self.y_bounds.unwrap_or_else(|| {
+            get_bounds(
+                self.series
+                    .iter()
+                    .flat_map(|series| series.points.iter().map(|(_, y)| *y)),
+            )
+        });
+    }
+
+    fn plot(&self, columns: u16, rows: u16) -> Canvas {
+        let mut canvas = Canvas::new(columns, rows, CanvasMarker::Braille);
+        let (x_bounds, y_bounds) = (self.get_x_bounds(), self.get_y_bounds());
+        let (width, height) = (canvas.get_width(), canvas.get_height());
+        for series in &self.series {
+            let points: Vec<(i32, i32)> = series
+                .points
+                .iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(|(x, y)| {
+                    (
+                        scale(*x, x_bounds, width),
+                        height as i32 - 1 - scale(*y, y_bounds, height),
+                    )
+                })
+                .collect();
+            if let [(x, y)] = points.as_slice() {
+                canvas.set_pixel(*x, *y, series.color);
+            }
+            for pair in points.windows(2) {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                canvas.draw_line(x0, y0, x1, y1, series.color);
+            }
+        }
+        return canvas;
+    }
+
+    fn overlay_legend(&self, rows: &mut [Vec<StringPlus>], start: usize) {
+        if !self.show_legend {
+            return;
+        }
+        for (row, series) in rows.iter_mut().zip(&self.series) {
+            let available = row.len().saturating_sub(start);
+            let entry: Vec<char> = ("• ".to_string() + &series.name)
+                .chars()
+                .take(available)
+                .collect();
+            let offset = row.len() - entry.len();
+            for (index, c) in entry.into_iter().enumerate() {
+                row[offset + index] = c.set_font_color(series.color);
+            }
+        }
+    }
+}
+
+impl Widget for LineChart {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>> {
+        let (x_bounds, y_bounds) = (self.get_x_bounds(), self.get_y_bounds());
+        let y_ticks = [y_bounds.1, (y_bounds.0 + y_bounds.1) / 2.0, y_bounds.0].map(format_value);
+        let label_width = y_ticks.iter().map(|tick| tick.len()).max().unwrap_or(0);
+        let plot_columns = (width as usize).saturating_sub(label_width + 1);
+        let plot_rows = (height as usize).saturating_sub(2);
+        if plot_columns == 0 || plot_rows == 0 {
+            return Vec::new();
+        }
+        let canvas = self.plot(plot_columns as u16, plot_rows as u16);
+        let mut rows: Vec<Vec<StringPlus>> = Vec::new();
+        for row in 0..plot_rows {
+            let tick = match row {
+                0 => y_ticks[0].as_str(),
+                _ if row == plot_rows - 1 => y_ticks[2].as_str(),
+                _ if row == (plot_rows - 1) / 2 && plot_rows > 4 => y_ticks[1].as_str(),
+                _ => "",
+            };
+            let mut cells: Vec<StringPlus> = format!("{:>width$}", tick, width = label_width)
+                .chars()
+                .map(|c| c.set_role(ThemeRole::Muted))
+                .collect();
+            cells.push('│'.set_role(ThemeRole::Border));
+            cells
+                .extend((0..plot_columns).map(|column| canvas.get_cell(column as u16, row as u16)));
+            rows.push(cells);
+        }
+        self.overlay_legend(&mut rows, label_width + 1);
+        let mut lines: Vec<Vec<StringPlus>> = rows.into_iter().map(merge_cells).collect();
+        lines.push(vec![
+            " ".repeat(label_width).into(),
+            ("└".to_string() + &"─".repeat(plot_columns)).set_role(ThemeRole::Border),
+        ]);
+        let mut x_ticks: Vec<char> = vec![' '; plot_columns];
+        let (min, mid, max) = (
+            format_value(x_bounds.0),
+            format_value((x_bounds.0 + x_bounds.1) / 2.0),
+            format_value(x_bounds.1),
+        );
+        let mut place = |text: &str, start: usize| {
+            if start + text.len() <= plot_columns
+                && x_ticks[start.saturating_sub(1)..(start + text.len() + 1).min(plot_columns)]
+                    .iter()
+                    .all(|c| *c == ' ')
+            {
+                for (index, c) in text.chars().enumerate() {
+                    x_ticks[start + index] = c;
+                }
+            }
+        };
+        place(&min, 0);
+        place(&max, plot_columns.saturating_sub(max.len()));
+        place(&mid, (plot_columns / 2).saturating_sub(mid.len() / 2));
+        lines.push(vec![
+            " ".repeat(label_width + 1).into(),
+            x_ticks
+                .into_iter()
+                .collect::<String>()
+                .set_role(ThemeRole::Muted),
+        ]);
+        return lines;
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    chart::{format_value, BarChart, LineChart, Series, Sparkline},
+    layout::Direction,
+    string_plus::StringPlus,
+    tui_enums::Color,
+    widget::Widget,
+};
+
+fn to_text(lines: &[Vec<StringPlus>]) -> Vec<String> {
+    return lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.to_string()).collect())
+        .collect();
+}
+
+#[test]
+fn test_format_value() -> Result<(), String> {
+    for (value, expected) in [
+        (3.0, "3"),
+        (-12.0, "-12"),
+        (2.5, "2.5"),
+        (0.25, "0.25"),
+        (1.0 / 3.0, "0.33"),
+    ] {
+        if format_value(value) != expected {
+            Err(format!("{} formatted as {:?}", value, format_value(value)))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_sparkline() -> Result<(), String> {
+    let sparkline = Sparkline::new(vec![0.0, 1.0, 2.0, 4.0, 8.0]);
+    let text = to_text(&sparkline.render(5, 1));
+    if text != [" ▁▂▄█"] {
+        Err(format!("{:?}", text))?;
+    }
+    let text = to_text(&sparkline.render(3, 2));
+    if text != ["  █", "▄██"] {
+        Err(format!("Expected last values on two rows: {:?}", text))?;
+    }
+    let lines = Sparkline::new(vec![0.0, 10.0])
+        .max(10.0)
+        .gradient(Color::RGB(0, 0, 0), Color::RGB(200, 100, 0))
+        .render(2, 1);
+    if lines[0].len() != 2 || lines[0][1].get_font_color() != Color::RGB(200, 100, 0) {
+        Err(format!("Gradient not applied: {:?}", lines[0]))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_horizontal_bar_chart() -> Result<(), String> {
+    let chart = BarChart::new(vec![("cpu".to_string(), 10.0), ("io".to_string(), 5.0)]);
+    let text = to_text(&chart.render(18, 5));
+    if text != ["cpu ███████████ 10", "io  █████▌      5"] {
+        Err(format!("{:?}", text))?;
+    }
+    let text = to_text(&chart.show_values(false).max(16.0).render(6, 1));
+    if text != ["c… █▉"] {
+        Err(format!("Expected a partial bar: {:?}", text))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_vertical_bar_chart() -> Result<(), String> {
+    let chart = BarChart::new(vec![
+        ("a".to_string(), 4.0),
+        ("b".to_string(), 1.0),
+        ("c".to_string(), 2.0),
+    ])
+    .direction(Direction::Vertical)
+    .bar_width(1)
+    .show_values(false);
+    let text = to_text(&chart.render(5, 3));
+    if text != ["█    ", "█ ▄ █", "a b c"] {
+        Err(format!("{:?}", text))?;
+    }
+    if chart.render(3, 3)[0].len() != 3 {
+        Err("Bars beyond the width were not dropped")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_line_chart() -> Result<(), String> {
+    let chart = LineChart::new(vec![
+        Series::new("up", vec![(0.0, 0.0), (10.0, 100.0)], Color::Green),
+        Series::new("flat", vec![(0.0, 50.0), (10.0, 50.0)], Color::Blue),
+    ]);
+    let text = to_text(&chart.render(24, 8));
+    if text.len() != 8 || text.iter().any(|line| line.chars().count() != 24) {
+        Err(format!("Chart not sized to the rect: {:?}", text))?;
+    }
+    if !text[0].starts_with("100│") || !text[5].starts_with("  0│") || !text[2].starts_with(" 50│")
+    {
+        Err(format!("Missing y ticks: {:?}", text))?;
+    }
+    if !text[0].ends_with("• up") || !text[1].ends_with("• flat") {
+        Err(format!("Missing legend: {:?}", text))?;
+    }
+    if text[6] != "   └────────────────────" || text[7] != "    0         5       10"
+    {
+        Err(format!("Missing x axis: {:?}", text[6..].to_vec()))?;
+    }
+    if !chart.render(3, 2).is_empty() {
+        Err("Rendered into a rect with no room for a plot")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_line_chart_out_of_bounds_points() -> Result<(), String> {
+    for y in [-1e12, 1e12, f64::MAX, f64::MIN] {
+        let chart = LineChart::new(vec![Series::new(
+            "a",
+            vec![(0.0, 0.0), (1.0, y)],
+            Color::Red,
+        )])
+        .y_bounds(0.0, 1.0)
+        .show_legend(false);
+        let text = to_text(&chart.render(40, 10));
+        if text.len() != 10 || text.iter().any(|line| line.chars().count() != 40) {
+            Err(format!("{}: {:?}", y, text))?;
+        }
+        let plotted = text[..8]
+            .iter()
+            .any(|line| line.chars().any(|c| ('\u{2801}'..='\u{28ff}').contains(&c)));
+        if !plotted {
+            Err(format!(
+                "{}: in-bounds part of the segment was not drawn {:?}",
+                y, text
+            ))?;
+        }
+    }
+    return Ok(());
+}
@@ -0,0 +1,309 @@
+use std::str::FromStr;
+
+use crate::{tui_enums::Color, tui_errors::ColorParseError};
+
+const ANSI_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+const NAMED_COLORS: [(&str, Color); 19] = [
+    ("black", Color::Black),
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("blue", Color::Blue),
+    ("magenta", Color::Magenta),
+    ("cyan", Color::Cyan),
+    ("white", Color::White),
+    ("brightblack", Color::BrightBlack),
+    ("brightred", Color::BrightRed),
+    ("brightgreen", Color::BrightGreen),
+    ("brightyellow", Color::BrightYellow),
+    ("brightblue", Color::BrightBlue),
+    ("brightmagenta", Color::BrightMagenta),
+    ("brightcyan", Color::BrightCyan),
+    ("brightwhite", Color::BrightWhite),
+    ("gray", Color::BrightBlack),
+    ("grey", Color::BrightBlack),
+    ("default", Color::Default),
+];
+
+fn cube_level(level: u8) -> u8 {
+    return match level {
+        0 => 0,
+        _ => 55 + level * 40,
+    };
+}
+
+fn channel_to_linear(channel: u8) -> f32 {
+    let channel = channel as f32 / 255.0;
+    if channel <= 0.03928 {
+        return channel / 12.92;
+    }
+    return ((channel + 0.055) / 1.055).powf(2.4);
+}
+
+fn to_u8(value: f32) -> u8 {
+    return (value * 255.0).round().clamp(0.0, 255.0) as u8;
+}
+
+fn lerp_u8(start: u8, end: u8, t: f32) -> u8 {
+    return (start as f32 + (end as f32 - start as f32) * t)
+        .round()
+        .clamp(0.0, 255.0) as u8;
+}
+
+fn hue_to_rgb(hue: f32, chroma: f32, offset: f32) -> (u8, u8, u8) {
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    return (to_u8(r + offset), to_u8(g + offset), to_u8(b + offset));
+}
+
+impl Color {
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        return match *self {
+            Color::Black => Some(ANSI_COLORS[0]),
+            Color::Red => Some(ANSI_COLORS[1]),
+            Color::Green => Some(ANSI_COLORS[2]),
+            Color::Yellow => Some(ANSI_COLORS[3]),
+            Color::Blue => Some(ANSI_COLORS[4]),
+            Color::Magenta => Some(ANSI_COLORS[5]),
+            Color::Cyan => Some(ANSI_COLORS[6]),
+            Color::White => Some(ANSI_COLORS[7]),
+            Color::BrightBlack => Some(ANSI_COLORS[8]),
+            Color::BrightRed => Some(ANSI_COLORS[9]),
+            Color::BrightGreen => Some(ANSI_COLORS[10]),
+            Color::BrightYellow => Some(ANSI_COLORS[11]),
+            Color::BrightBlue => Some(ANSI_COLORS[12]),
+            Color::BrightMagenta => Some(ANSI_COLORS[13]),
+            Color::BrightCyan => Some(ANSI_COLORS[14]),
+            Color::BrightWhite => Some(ANSI_COLORS[15]),
+            Color::CC256(code @ 0..=15) => Some(ANSI_COLORS[code as usize]),
+            Color::CC256(code @ 16..=231) => {
+                let index = code - 16;
+                Some((
+                    cube_level(index / 36),
+                    cube_level((index / 6) % 6),
+                    cube_level(index % 6),
+                ))
+            }
+            Color::CC256(code) => {
+                let level = 8 + (code - 232) * 10;
+                Some((level, level, level))
+            }
+            Color::RGB(r, g, b) => Some((r, g, b)),
+            Color::Default => None,
+        };
+    }
+
+    pub fn to_hex(&self) -> Option<String> {
+        let (r, g, b) = self.to_rgb()?;
+        return Some(format!("#{:02x}{:02x}{:02x}", r, g, b));
+    }
+
+    pub fn to_hsl(&self) -> Option<(f32, f32, f32)> {
+        let (r, g, b) = self.to_rgb()?;
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return Some((0.0, 0.0, lightness));
+        }
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        return Some((hue(r, g, b, max, delta), saturation, lightness));
+    }
+
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let (r, g, b) = hue_to_rgb(hue, chroma, lightness - chroma / 2.0);
+        return Color::RGB(r, g, b);
+    }
+
+    pub fn to_hsv(&self) -> Option<(f32, f32, f32)> {
+        let (r, g, b) = self.to_rgb()?;
+        let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        if delta == 0.0 {
+            return Some((0.0, 0.0, max));
+        }
+        return Some((hue(r, g, b, max, delta), delta / max, max));
+    }
+
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+        let chroma = value * saturation;
+        let (r, g, b) = hue_to_rgb(hue, chroma, value - chroma);
+        return Color::RGB(r, g, b);
+    }
+
+    pub fn lerp(&self, other: Color, t: f32) -> Color {
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return match t < 0.5 {
+                true => *self,
+                false => other,
+            };
+        };
+        let t = t.clamp(0.0, 1.0);
+        return Color::RGB(lerp_u8(r1, r2, t), lerp_u8(g1, g2, t), lerp_u8(b1, b2, t));
+    }
+
+    pub fn blend(&self, other: Color, alpha: f32) -> Color {
+        return self.lerp(other, alpha);
+    }
+
+    pub fn lighten(&self, amount: f32) -> Color {
+        let Some((hue, saturation, lightness)) = self.to_hsl() else {
+            return *self;
+        };
+        return Color::from_hsl(hue, saturation, lightness + (1.0 - lightness) * amount);
+    }
+
+    pub fn darken(&self, amount: f32) -> Color {
+        let Some((hue, saturation, lightness)) = self.to_hsl() else {
+            return *self;
+        };
+        return Color::from_hsl(hue, saturation, lightness * (1.0 - amount));
+    }
+
+    pub fn relative_luminance(&self) -> Option<f32> {
+        let (r, g, b) = self.to_rgb()?;
+        return Some(
+            0.2126 * channel_to_linear(r)
+                + 0.7152 * channel_to_linear(g)
+                + 0.0722 * channel_to_linear(b),
+        );
+    }
+
+    pub fn contrast_ratio(&self, other: Color) -> Option<f32> {
+        let luminance1 = self.relative_luminance()?;
+        let luminance2 = other.relative_luminance()?;
+        let (lighter, darker) = match luminance1 > luminance2 {
+            true => (luminance1, luminance2),
+            false => (luminance2, luminance1),
+        };
+        return Some((lighter + 0.05) / (darker + 0.05));
+    }
+
+    pub fn gradient(start: Color, end: Color, steps: usize) -> Vec<Color> {
+        return match steps {
+            0 => Vec::new(),
+            1 => vec![start],
+            _ => (0..steps)
+                .map(|step| start.lerp(end, step as f32 / (steps - 1) as f32))
+                .collect(),
+        };
+    }
+}
+
+fn hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+    let hue = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    return hue.rem_euclid(360.0);
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |index: usize, length: usize| -> Option<u8> {
+        let value = u8::from_str_radix(&hex[index * length..(index + 1) * length], 16).ok()?;
+        return Some(match length {
+            1 => value * 17,
+            _ => value,
+        });
+    };
+    let length = match hex.len() {
+        3 => 1,
+        6 => 2,
+        _ => return None,
+    };
+    return Some(Color::RGB(
+        channel(0, length)?,
+        channel(1, length)?,
+        channel(2, length)?,
+    ));
+}
+
+fn parse_function<'a>(string: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let arguments = string.strip_prefix(name)?.trim_start();
+    let arguments = arguments.strip_prefix('(')?.strip_suffix(')')?;
+    return Some(
+        arguments
+            .split(',')
+            .map(|argument| argument.trim())
+            .collect(),
+    );
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(string: &str) -> Result<Color, ColorParseError> {
+        let error = ColorParseError {
+            input: string.to_string(),
+        };
+        let normalized: String = string
+            .trim()
+            .to_ascii_lowercase()
+            .chars()
+            .filter(|c| *c != '_' && *c != '-' && *c != ' ')
+            .collect();
+        if let Some(hex) = normalized.strip_prefix('#') {
+            return parse_hex(hex).ok_or(error);
+        }
+        if let Some(arguments) = parse_function(&normalized, "rgb") {
+            let [r, g, b] = arguments.as_slice() else {
+                return Err(error);
+            };
+            let (Ok(r), Ok(g), Ok(b)) = (r.parse(), g.parse(), b.parse()) else {
+                return Err(error);
+            };
+            return Ok(Color::RGB(r, g, b));
+        }
+        if let Some(arguments) = parse_function(&normalized, "ansi") {
+            let [code] = arguments.as_slice() else {
+                return Err(error);
+            };
+            return code.parse().map(Color::CC256).map_err(|_| error);
+        }
+        return NAMED_COLORS
+            .iter()
+            .find(|(name, _)| *name == normalized)
+            .map(|(_, color)| *color)
+            .ok_or(error);
+    }
+}
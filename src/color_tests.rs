@@ -0,0 +1,85 @@
+use crate::tui_enums::Color;
+
+#[test]
+fn test_parse_colors() -> Result<(), String> {
+    let cases = [
+        ("#1e1e2e", Color::RGB(0x1e, 0x1e, 0x2e)),
+        ("#FFF", Color::RGB(255, 255, 255)),
+        ("bright_red", Color::BrightRed),
+        ("Grey", Color::BrightBlack),
+        ("ansi(240)", Color::CC256(240)),
+        ("rgb(1, 2, 3)", Color::RGB(1, 2, 3)),
+    ];
+    for (string, expected) in cases {
+        let color: Color = string.parse().map_err(|error| format!("{}", error))?;
+        if color != expected {
+            Err(format!("{:?}: {:?} != {:?}", string, color, expected))?;
+        }
+    }
+    for string in ["#12345", "rgb(1,2)", "ansi(256)", "purple"] {
+        if string.parse::<Color>().is_ok() {
+            Err(format!("{:?} should not parse", string))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_hsl_hsv_round_trip() -> Result<(), String> {
+    let color = Color::RGB(30, 144, 255);
+    let (h, s, l) = color.to_hsl().ok_or("No HSL")?;
+    let (hv, sv, v) = color.to_hsv().ok_or("No HSV")?;
+    if Color::from_hsl(h, s, l) != color || Color::from_hsv(hv, sv, v) != color {
+        Err(format!("{:?} {:?}", (h, s, l), (hv, sv, v)))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_cc256_to_rgb() -> Result<(), String> {
+    let cases = [
+        (Color::CC256(16), (0, 0, 0)),
+        (Color::CC256(196), (255, 0, 0)),
+        (Color::CC256(244), (128, 128, 128)),
+    ];
+    for (color, expected) in cases {
+        if color.to_rgb() != Some(expected) {
+            Err(format!(
+                "{:?}: {:?} != {:?}",
+                color,
+                color.to_rgb(),
+                expected
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_blend_and_gradient() -> Result<(), String> {
+    let gradient = Color::gradient(Color::RGB(0, 0, 0), Color::RGB(255, 255, 255), 3);
+    let expected = vec![
+        Color::RGB(0, 0, 0),
+        Color::RGB(128, 128, 128),
+        Color::RGB(255, 255, 255),
+    ];
+    if gradient != expected {
+        Err(format!("{:?} != {:?}", gradient, expected))?;
+    }
+    let darker = Color::RGB(200, 100, 50).darken(0.2);
+    if darker.to_hsl().ok_or("No HSL")?.2 >= Color::RGB(200, 100, 50).to_hsl().ok_or("")?.2 {
+        Err(format!("{:?}", darker))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_contrast_ratio() -> Result<(), String> {
+    let ratio = Color::RGB(0, 0, 0)
+        .contrast_ratio(Color::RGB(255, 255, 255))
+        .ok_or("No Ratio")?;
+    if (ratio - 21.0).abs() > 0.01 {
+        Err(format!("{}", ratio))?;
+    }
+    return Ok(());
+}
@@ -1,4 +1,4 @@
-use crate::tui_enums::{Color, ThreeBool};
+use crate::tui_enums::{Color, DecLineMode, ThreeBool, UnderlineStyle};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct FontSettings {
@@ -9,7 +9,14 @@ pub struct FontSettings {
     pub is_italics: ThreeBool,
     pub is_inverted: ThreeBool,
     pub is_blinking: ThreeBool,
+    pub is_strikethrough: ThreeBool,
+    pub is_dim: ThreeBool,
+    pub is_overlined: ThreeBool,
+    pub is_hidden: ThreeBool,
+    pub underline_style: UnderlineStyle,
+    pub underline_color: Color,
     pub is_dec_line: bool,
+    pub dec_line_mode: DecLineMode,
 }
 
 impl Default for FontSettings {
@@ -22,7 +29,170 @@ impl Default for FontSettings {
             is_italics: ThreeBool::Default,
             is_inverted: ThreeBool::Default,
             is_blinking: ThreeBool::Default,
+            is_strikethrough: ThreeBool::Default,
+            is_dim: ThreeBool::Default,
+            is_overlined: ThreeBool::Default,
+            is_hidden: ThreeBool::Default,
+            underline_style: UnderlineStyle::Default,
+            underline_color: Color::Default,
             is_dec_line: false,
+            dec_line_mode: DecLineMode::Default,
         }
     }
 }
+
+fn overlay_three_bool(base: ThreeBool, top: ThreeBool) -> ThreeBool {
+    return match top {
+        ThreeBool::Default => base,
+        _ => top,
+    };
+}
+
+fn overlay_color(base: Color, top: Color) -> Color {
+    return match top {
+        Color::Default => base,
+        _ => top,
+    };
+}
+
+impl FontSettings {
+    pub fn overlay(&self, top: &FontSettings) -> FontSettings {
+        return FontSettings {
+            font_color: overlay_color(self.font_color, top.font_color),
+            background_color: overlay_color(self.background_color, top.background_color),
+            is_bold: overlay_three_bool(self.is_bold, top.is_bold),
+            is_underlined: overlay_three_bool(self.is_underlined, top.is_underlined),
+            is_italics: overlay_three_bool(self.is_italics, top.is_italics),
+            is_inverted: overlay_three_bool(self.is_inverted, top.is_inverted),
+            is_blinking: overlay_three_bool(self.is_blinking, top.is_blinking),
+            is_strikethrough: overlay_three_bool(self.is_strikethrough, top.is_strikethrough),
+            is_dim: overlay_three_bool(self.is_dim, top.is_dim),
+            is_overlined: overlay_three_bool(self.is_overlined, top.is_overlined),
+            is_hidden: overlay_three_bool(self.is_hidden, top.is_hidden),
+            underline_style: match top.underline_style {
+                UnderlineStyle::Default => self.underline_style,
+                _ => top.underline_style,
+            },
+            underline_color: overlay_color(self.underline_color, top.underline_color),
+            is_dec_line: self.is_dec_line || top.is_dec_line,
+            dec_line_mode: match top.dec_line_mode {
+                DecLineMode::Default => self.dec_line_mode,
+                _ => top.dec_line_mode,
+            },
+        };
+    }
+}
+
+fn get_palette_index(color: Color) -> Option<u8> {
+    return match color {
+        Color::Black => Some(0),
+        Color::Red => Some(1),
+        Color::Green => Some(2),
+        Color::Yellow => Some(3),
+        Color::Blue => Some(4),
+        Color::Magenta => Some(5),
+        Color::Cyan => Some(6),
+        Color::White => Some(7),
+        Color::BrightBlack => Some(8),
+        Color::BrightRed => Some(9),
+        Color::BrightGreen => Some(10),
+        Color::BrightYellow => Some(11),
+        Color::BrightBlue => Some(12),
+        Color::BrightMagenta => Some(13),
+        Color::BrightCyan => Some(14),
+        Color::BrightWhite => Some(15),
+        Color::CC256(code) => Some(code),
+        Color::RGB(_, _, _) | Color::Default => None,
+    };
+}
+
+fn get_color_code(color: Color, base: u8) -> String {
+    return match color {
+        Color::Default => (base + 9).to_string(),
+        Color::CC256(code) => (base + 8).to_string() + ";5;" + &code.to_string(),
+        Color::RGB(r, g, b) => {
+            (base + 8).to_string()
+                + ";2;"
+                + &r.to_string()
+                + ";"
+                + &g.to_string()
+                + ";"
+                + &b.to_string()
+        }
+        _ => {
+            let index = get_palette_index(color).unwrap_or(0);
+            match index < 8 {
+                true => (base + index).to_string(),
+                false => (base + 60 + index - 8).to_string(),
+            }
+        }
+    };
+}
+
+fn get_intensity_code(is_bold: ThreeBool, is_dim: ThreeBool) -> String {
+    let mut code: String = "22".into();
+    if let ThreeBool::True = is_bold {
+        code += ";1";
+    }
+    if let ThreeBool::True = is_dim {
+        code += ";2";
+    }
+    return code;
+}
+
+fn get_underlined_code(is_underlined: ThreeBool, underline_style: UnderlineStyle) -> &'static str {
+    return match (is_underlined, underline_style) {
+        (ThreeBool::False, _) => "24",
+        (ThreeBool::Default, UnderlineStyle::Default) => "24",
+        (_, UnderlineStyle::Double) => "4:2",
+        (_, UnderlineStyle::Curly) => "4:3",
+        (_, UnderlineStyle::Single | UnderlineStyle::Default) => "4",
+    };
+}
+
+fn get_underline_color_code(color: Color) -> String {
+    return match (color, get_palette_index(color)) {
+        (Color::RGB(r, g, b), _) => {
+            "58:2::".to_string() + &r.to_string() + ":" + &g.to_string() + ":" + &b.to_string()
+        }
+        (_, Some(index)) => "58:5:".to_string() + &index.to_string(),
+        (_, None) => "59".into(),
+    };
+}
+
+fn get_toggle_code(value: ThreeBool, on: &'static str, off: &'static str) -> &'static str {
+    return match value {
+        ThreeBool::True => on,
+        ThreeBool::False | ThreeBool::Default => off,
+    };
+}
+
+impl FontSettings {
+    pub(crate) fn get_sgr_code(&self, defaults: &FontSettings) -> String {
+        let font_settings = defaults.overlay(self);
+        let mut code: String = String::from("\x1b[");
+        code += &get_color_code(font_settings.font_color, 30);
+        code += ";";
+        code += &get_color_code(font_settings.background_color, 40);
+        code += ";";
+        code += &get_intensity_code(font_settings.is_bold, font_settings.is_dim);
+        code += ";";
+        code += get_underlined_code(font_settings.is_underlined, font_settings.underline_style);
+        code += ";";
+        code += get_toggle_code(font_settings.is_italics, "3", "23");
+        code += ";";
+        code += get_toggle_code(font_settings.is_inverted, "7", "27");
+        code += ";";
+        code += get_toggle_code(font_settings.is_blinking, "5", "25");
+        code += ";";
+        code += get_toggle_code(font_settings.is_strikethrough, "9", "29");
+        code += ";";
+        code += get_toggle_code(font_settings.is_overlined, "53", "55");
+        code += ";";
+        code += get_toggle_code(font_settings.is_hidden, "8", "28");
+        code += "m\x1b[";
+        code += &get_underline_color_code(font_settings.underline_color);
+        code += "m";
+        return code;
+    }
+}
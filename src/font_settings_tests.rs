@@ -0,0 +1,109 @@
+use crate::font_settings::FontSettings;
+use crate::tui_enums::{Color, ThreeBool, UnderlineStyle};
+
+#[test]
+fn test_sgr_code_defaults() -> Result<(), String> {
+    let code = FontSettings::default().get_sgr_code(&FontSettings::default());
+    let expected = "\x1b[39;49;22;24;23;27;25;29;55;28m\x1b[59m";
+    if code != expected {
+        Err(format!("{:?} != {:?}", code, expected))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_sgr_code_colors() -> Result<(), String> {
+    let cases = [
+        (Color::Red, Color::BrightCyan, "31;106"),
+        (Color::CC256(208), Color::Black, "38;5;208;40"),
+        (
+            Color::RGB(1, 2, 3),
+            Color::RGB(4, 5, 6),
+            "38;2;1;2;3;48;2;4;5;6",
+        ),
+    ];
+    for (font_color, background_color, colors) in cases {
+        let font_settings = FontSettings {
+            font_color,
+            background_color,
+            ..Default::default()
+        };
+        let code = font_settings.get_sgr_code(&FontSettings::default());
+        let expected = "\x1b[".to_string() + colors + ";22;24;23;27;25;29;55;28m\x1b[59m";
+        if code != expected {
+            Err(format!("{:?} != {:?}", code, expected))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_sgr_code_underline() -> Result<(), String> {
+    let cases = [
+        (ThreeBool::True, UnderlineStyle::Default, "4"),
+        (ThreeBool::True, UnderlineStyle::Curly, "4:3"),
+        (ThreeBool::Default, UnderlineStyle::Double, "4:2"),
+        (ThreeBool::Default, UnderlineStyle::Single, "4"),
+        (ThreeBool::False, UnderlineStyle::Double, "24"),
+        (ThreeBool::Default, UnderlineStyle::Default, "24"),
+    ];
+    for (is_underlined, underline_style, underline) in cases {
+        let font_settings = FontSettings {
+            is_underlined,
+            underline_style,
+            ..Default::default()
+        };
+        let code = font_settings.get_sgr_code(&FontSettings::default());
+        let expected = "\x1b[39;49;22;".to_string() + underline + ";23;27;25;29;55;28m\x1b[59m";
+        if code != expected {
+            Err(format!(
+                "{:?}/{:?}: {:?} != {:?}",
+                is_underlined, underline_style, code, expected
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_sgr_code_underline_color() -> Result<(), String> {
+    let cases = [
+        (Color::Red, "58:5:1"),
+        (Color::BrightWhite, "58:5:15"),
+        (Color::CC256(200), "58:5:200"),
+        (Color::RGB(10, 20, 30), "58:2::10:20:30"),
+    ];
+    for (underline_color, color_code) in cases {
+        let font_settings = FontSettings {
+            underline_color,
+            ..Default::default()
+        };
+        let code = font_settings.get_sgr_code(&FontSettings::default());
+        let expected = "\x1b[39;49;22;24;23;27;25;29;55;28m\x1b[".to_string() + color_code + "m";
+        if code != expected {
+            Err(format!("{:?} != {:?}", code, expected))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_sgr_code_terminal_defaults() -> Result<(), String> {
+    let defaults = FontSettings {
+        is_italics: ThreeBool::True,
+        is_bold: ThreeBool::True,
+        underline_style: UnderlineStyle::Curly,
+        ..Default::default()
+    };
+    let font_settings = FontSettings {
+        is_bold: ThreeBool::False,
+        is_dim: ThreeBool::True,
+        ..Default::default()
+    };
+    let code = font_settings.get_sgr_code(&defaults);
+    let expected = "\x1b[39;49;22;2;4:3;3;27;25;29;55;28m\x1b[59m";
+    if code != expected {
+        Err(format!("{:?} != {:?}", code, expected))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use crate::{base64, png, tui_errors::ImageSizeError};
+
+const KITTY_CHUNK_LENGTH: usize = 4096;
+const SIXEL_BAND_HEIGHT: usize = 6;
+pub const MAX_SIXEL_COLORS: usize = 256;
+
+type Rgb = [u8; 3];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Result<Image, ImageSizeError> {
+        if width as usize * height as usize * 4 != pixels.len() {
+            return Err(ImageSizeError {
+                width,
+                height,
+                length: pixels.len(),
+            });
+        }
+        return Ok(Image {
+            width,
+            height,
+            pixels,
+        });
+    }
+
+    pub fn get_width(&self) -> u32 {
+        return self.width;
+    }
+
+    pub fn get_height(&self) -> u32 {
+        return self.height;
+    }
+
+    pub fn get_pixels(&self) -> &[u8] {
+        return &self.pixels;
+    }
+
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        return Some([
+            self.pixels[index],
+            self.pixels[index + 1],
+            self.pixels[index + 2],
+            self.pixels[index + 3],
+        ]);
+    }
+
+    pub fn resize(&self, width: u32, height: u32) -> Image {
+        if width == self.width && height == self.height {
+            return self.clone();
+        }
+        let mut pixels: Vec<u8> = Vec::with_capacity(width as usize * height as usize * 4);
+        for y in 0..height as u64 {
+            let source_y = (y * self.height as u64 / height as u64) as u32;
+            for x in 0..width as u64 {
+                let source_x = (x * self.width as u64 / width as u64) as u32;
+                pixels.extend_from_slice(&self.get_pixel(source_x, source_y).unwrap_or([0; 4]));
+            }
+        }
+        return Image {
+            width,
+            height,
+            pixels,
+        };
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        return png::encode_rgba(self.width, self.height, &self.pixels);
+    }
+}
+
+struct ColorBox {
+    colors: Vec<(Rgb, u32)>,
+}
+
+impl ColorBox {
+    fn get_widest_channel(&self) -> (usize, u8) {
+        let mut widest: (usize, u8) = (0, 0);
+        for channel in 0..3 {
+            let values = self.colors.iter().map(|(color, _)| color[channel]);
+            let range = values.clone().max().unwrap_or(0) - values.min().unwrap_or(0);
+            if range > widest.1 {
+                widest = (channel, range);
+            }
+        }
+        return widest;
+    }
+
+    fn get_average(&self) -> Rgb {
+        let mut sums: [u64; 3] = [0; 3];
+        let mut total: u64 = 0;
+        for (color, count) in &self.colors {
+            for channel in 0..3 {
+                sums[channel] += color[channel] as u64 * *count as u64;
+            }
+            total += *count as u64;
+        }
+        let total = total.max(1);
+        return sums.map(|sum| ((sum + total / 2) / total) as u8);
+    }
+
+    fn split(mut self) -> (ColorBox, ColorBox) {
+        let (channel, _) = self.get_widest_channel();
+        self.colors.sort_by_key(|(color, _)| color[channel]);
+        let half: u64 = self
+            .colors
+            .iter()
+            .map(|(_, count)| *count as u64)
+            .sum::<u64>()
+            / 2;
+        let mut seen: u64 = 0;
+        let mut median = 1;
+        for (index, (_, count)) in self.colors.iter().enumerate() {
+            seen += *count as u64;
+            if seen >= half {
+                median = (index + 1).clamp(1, self.colors.len() - 1);
+                break;
+            }
+        }
+        let upper = self.colors.split_off(median);
+        return (self, ColorBox { colors: upper });
+    }
+}
+
+fn distance(a: Rgb, b: Rgb) -> u32 {
+    return (0..3)
+        .map(|channel| (a[channel] as i32 - b[channel] as i32).pow(2) as u32)
+        .sum();
+}
+
+pub fn quantize(image: &Image, max_colors: usize) -> (Vec<Rgb>, Vec<Option<u8>>) {
+    let max_colors = max_colors.clamp(1, MAX_SIXEL_COLORS);
+    let mut histogram: HashMap<Rgb, u32> = HashMap::new();
+    for pixel in image.pixels.chunks(4) {
+        if pixel[3] >= 128 {
+            *histogram.entry([pixel[0], pixel[1], pixel[2]]).or_insert(0) += 1;
+        }
+    }
+    let mut colors: Vec<(Rgb, u32)> = histogram.into_iter().collect();
+    colors.sort();
+    let palette: Vec<Rgb> = match colors.len() <= max_colors {
+        true => colors.iter().map(|(color, _)| *color).collect(),
+        false => {
+            let mut boxes: Vec<ColorBox> = vec![ColorBox { colors }];
+            while boxes.len() < max_colors {
+                let Some((index, _)) = boxes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, color_box)| color_box.colors.len() > 1)
+                    .max_by_key(|(_, color_box)| color_box.get_widest_channel().1)
+                else {
+                    break;
+                };
+                let (lower, upper) = boxes.swap_remove(index).split();
+                boxes.push(lower);
+                boxes.push(upper);
+            }
+            boxes
+                .iter()
+                .map(|color_box| color_box.get_average())
+                .collect()
+        }
+    };
+    let mut lookup: HashMap<Rgb, u8> = HashMap::new();
+    let indices = image
+        .pixels
+        .chunks(4)
+        .map(|pixel| {
+            if pixel[3] < 128 {
+                return None;
+            }
+            let color: Rgb = [pixel[0], pixel[1], pixel[2]];
+            let index = *lookup.entry(color).or_insert_with(|| {
+                (0..palette.len())
+                    .min_by_key(|index| distance(palette[*index], color))
+                    .unwrap_or(0) as u8
+            });
+            return Some(index);
+        })
+        .collect();
+    return (palette, indices);
+}
+
+fn push_sixel_run(output: &mut String, sixel: u8, count: usize) {
+    let sixel = (sixel + 63) as char;
+    if count > 3 {
+        *output += &("!".to_string() + &count.to_string());
+        output.push(sixel);
+    } else {
+        *output += &sixel.to_string().repeat(count);
+    }
+}
+
+fn encode_sixel_row(output: &mut String, sixels: &[u8]) {
+    let length = sixels.len() - sixels.iter().rev().take_while(|sixel| **sixel == 0).count();
+    let mut run: Option<(u8, usize)> = None;
+    for sixel in &sixels[..length] {
+        run = match run {
+            Some((previous, count)) if previous == *sixel => Some((previous, count + 1)),
+            Some((previous, count)) => {
+                push_sixel_run(output, previous, count);
+                Some((*sixel, 1))
+            }
+            None => Some((*sixel, 1)),
+        };
+    }
+    if let Some((sixel, count)) = run {
+        push_sixel_run(output, sixel, count);
+    }
+}
+
+pub fn encode_sixel(image: &Image) -> String {
+    let (palette, indices) = quantize(image, MAX_SIXEL_COLORS);
+    let (width, height) = (image.width as usize, image.height as usize);
+    let mut output: String =
+        "\x1bP0;1;0q\"1;1;".to_string() + &width.to_string() + ";" + &height.to_string();
+    for (index, color) in palette.iter().enumerate() {
+        let [r, g, b] = color.map(|channel| ((channel as u32 * 100 + 127) / 255).to_string());
+        output += &("#".to_string() + &index.to_string() + ";2;" + &r + ";" + &g + ";" + &b);
+    }
+    for band_top in (0..height).step_by(SIXEL_BAND_HEIGHT) {
+        let mut rows: HashMap<u8, Vec<u8>> = HashMap::new();
+        for offset in 0..SIXEL_BAND_HEIGHT.min(height - band_top) {
+            let row = (band_top + offset) * width;
+            for x in 0..width {
+                if let Some(index) = indices[row + x] {
+                    rows.entry(index).or_insert_with(|| vec![0; width])[x] |= 1 << offset;
+                }
+            }
+        }
+        let mut colors: Vec<&u8> = rows.keys().collect();
+        colors.sort();
+        for (position, index) in colors.into_iter().enumerate() {
+            if position != 0 {
+                output.push('$');
+            }
+            output += &("#".to_string() + &index.to_string());
+            encode_sixel_row(&mut output, &rows[index]);
+        }
+        output.push('-');
+    }
+    output += "\x1b\\";
+    return output;
+}
+
+pub fn encode_kitty(image: &Image, columns: u16, rows: u16, id: u32) -> String {
+    let encoded = base64::encode(&image.pixels);
+    let mut chunks = encoded.as_bytes().chunks(KITTY_CHUNK_LENGTH).peekable();
+    let mut output: String = String::new();
+    let mut control: String = "a=T,f=32,q=2,C=1,s=".to_string()
+        + &image.width.to_string()
+        + ",v="
+        + &image.height.to_string()
+        + ",c="
+        + &columns.to_string()
+        + ",r="
+        + &rows.to_string()
+        + ",i="
+        + &id.to_string();
+    if chunks.peek().is_none() {
+        return "\x1b_G".to_string() + &control + "\x1b\\";
+    }
+    while let Some(chunk) = chunks.next() {
+        let more = match chunks.peek() {
+            Some(_) => ",m=1;",
+            None => ",m=0;",
+        };
+        output += &("\x1b_G".to_string() + &control + more);
+        output += &String::from_utf8_lossy(chunk);
+        output += "\x1b\\";
+        control = "q=2".to_string();
+    }
+    return output;
+}
+
+pub fn encode_iterm2(image: &Image, columns: u16, rows: u16) -> String {
+    let png = image.to_png();
+    return "\x1b]1337;File=inline=1;size=".to_string()
+        + &png.len().to_string()
+        + ";width="
+        + &columns.to_string()
+        + ";height="
+        + &rows.to_string()
+        + ";preserveAspectRatio=0:"
+        + &base64::encode(&png)
+        + "\x07";
+}
@@ -0,0 +1,100 @@
+use crate::{
+    image::{encode_iterm2, encode_kitty, encode_sixel, quantize, Image},
+    png::{adler32, crc32},
+};
+
+fn checkerboard(width: u32, height: u32) -> Image {
+    let mut pixels: Vec<u8> = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            match (x + y) % 2 {
+                0 => pixels.extend_from_slice(&[255, 0, 0, 255]),
+                _ => pixels.extend_from_slice(&[0, 0, 255, 255]),
+            }
+        }
+    }
+    return Image::from_rgba(width, height, pixels).unwrap();
+}
+
+#[test]
+fn test_image_size_validation() -> Result<(), String> {
+    if Image::from_rgba(2, 2, vec![0; 15]).is_ok() {
+        Err("Accepted a short pixel buffer")?;
+    }
+    let image = checkerboard(2, 2).resize(4, 4);
+    if image.get_pixel(1, 1) != Some([255, 0, 0, 255])
+        || image.get_pixel(2, 1) != Some([0, 0, 255, 255])
+    {
+        Err(format!("{:?}", image))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_checksums() -> Result<(), String> {
+    if crc32(b"123456789") != 0xcbf4_3926 || crc32(b"IEND") != 0xae42_6082 {
+        Err(format!("{:08x}", crc32(b"123456789")))?;
+    }
+    if adler32(b"Wikipedia") != 0x11e6_0398 {
+        Err(format!("{:08x}", adler32(b"Wikipedia")))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_quantize() -> Result<(), String> {
+    let mut pixels: Vec<u8> = Vec::new();
+    for value in 0..=255u8 {
+        pixels.extend_from_slice(&[value, 255 - value, value / 2, 255]);
+    }
+    pixels.extend_from_slice(&[0, 0, 0, 0]);
+    let image = Image::from_rgba(257, 1, pixels).map_err(|error| error.to_string())?;
+    let (palette, indices) = quantize(&image, 16);
+    if palette.len() != 16 {
+        Err(format!("Palette has {} colors", palette.len()))?;
+    }
+    if indices[256].is_some() || indices[..256].iter().any(|index| index.is_none()) {
+        Err("Transparency not preserved")?;
+    }
+    let [r, g, _] = palette[indices[0].unwrap() as usize];
+    if r > 32 || g < 223 {
+        Err(format!(
+            "Poor match for first pixel: {:?}",
+            palette[indices[0].unwrap() as usize]
+        ))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_encode_sixel() -> Result<(), String> {
+    let sixel = encode_sixel(&checkerboard(8, 2));
+    let expected = "\x1bP0;1;0q\"1;1;8;2#0;2;0;0;100#1;2;100;0;0#0A@A@A@A@$#1@A@A@A@A-\x1b\\";
+    if sixel != expected {
+        Err(format!("{:?} != {:?}", sixel, expected))?;
+    }
+    let sixel = encode_sixel(&Image::from_rgba(6, 1, vec![255; 24]).unwrap());
+    if !sixel.contains("#0!6@-") {
+        Err(format!("Run not encoded: {:?}", sixel))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_encode_kitty_and_iterm2() -> Result<(), String> {
+    let image = checkerboard(64, 32);
+    let kitty = encode_kitty(&image, 8, 4, 7);
+    if !kitty.starts_with("\x1b_Ga=T,f=32,q=2,C=1,s=64,v=32,c=8,r=4,i=7,m=1;")
+        || kitty.matches("\x1b_G").count() != 3
+        || !kitty.contains("\x1b_Gq=2,m=0;")
+    {
+        Err(format!("{:?}", &kitty[..80]))?;
+    }
+    let iterm2 = encode_iterm2(&image, 8, 4);
+    if !iterm2.starts_with("\x1b]1337;File=inline=1;size=")
+        || !iterm2.contains(";width=8;height=4;preserveAspectRatio=0:iVBORw0KGgo")
+    {
+        Err(format!("{:?}", &iterm2[..80]))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,204 @@
+use crate::rect::Rect;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    Length(u16),
+    Percentage(u16),
+    Ratio(u32, u32),
+    Min(u16),
+    Max(u16),
+    Fill(u16),
+}
+
+#[derive(Clone, Debug)]
+pub struct Layout {
+    direction: Direction,
+    constraints: Vec<Constraint>,
+    horizontal_margin: u16,
+    vertical_margin: u16,
+    spacing: u16,
+}
+
+impl Constraint {
+    fn get_base(&self, total: u16) -> u16 {
+        return match *self {
+            Constraint::Length(length) => length,
+            Constraint::Percentage(percentage) => {
+                (total as u32 * percentage.min(100) as u32 / 100) as u16
+            }
+            Constraint::Ratio(_, 0) => 0,
+            Constraint::Ratio(numerator, denominator) => {
+                (total as u64 * numerator.min(denominator) as u64 / denominator as u64) as u16
+            }
+            Constraint::Min(min) => min,
+            Constraint::Max(_) | Constraint::Fill(_) => 0,
+        };
+    }
+
+    fn get_weight(&self, has_fill: bool) -> u32 {
+        return match *self {
+            Constraint::Fill(weight) => weight as u32,
+            Constraint::Min(_) | Constraint::Max(_) if !has_fill => 1,
+            _ => 0,
+        };
+    }
+
+    fn get_cap(&self) -> u16 {
+        return match *self {
+            Constraint::Max(max) => max,
+            _ => u16::MAX,
+        };
+    }
+}
+
+fn shrink(sizes: &mut [u16], constraints: &[Constraint], mut excess: u32) {
+    for pass in 0..2 {
+        for (size, constraint) in sizes.iter_mut().zip(constraints).rev() {
+            let floor = match (pass, constraint) {
+                (0, Constraint::Min(min)) => *min,
+                _ => 0,
+            };
+            let reduction = (size.saturating_sub(floor) as u32).min(excess);
+            *size -= reduction as u16;
+            excess -= reduction;
+        }
+    }
+}
+
+fn grow(sizes: &mut [u16], constraints: &[Constraint], mut remaining: u32) {
+    let has_fill = constraints
+        .iter()
+        .any(|constraint| matches!(constraint, Constraint::Fill(_)));
+    loop {
+        let growable: Vec<usize> = (0..sizes.len())
+            .filter(|&index| {
+                constraints[index].get_weight(has_fill) > 0
+                    && sizes[index] < constraints[index].get_cap()
+            })
+            .collect();
+        let total_weight: u32 = growable
+            .iter()
+            .map(|&index| constraints[index].get_weight(has_fill))
+            .sum();
+        if remaining == 0 || total_weight == 0 {
+            return;
+        }
+        let mut distributed: u32 = 0;
+        for &index in &growable {
+            let share = remaining as u64 * constraints[index].get_weight(has_fill) as u64
+                / total_weight as u64;
+            let share = (share as u32).min((constraints[index].get_cap() - sizes[index]) as u32);
+            sizes[index] += share as u16;
+            distributed += share;
+        }
+        if distributed == 0 {
+            for &index in &growable {
+                if remaining == distributed {
+                    break;
+                }
+                sizes[index] += 1;
+                distributed += 1;
+            }
+        }
+        remaining -= distributed;
+    }
+}
+
+impl Layout {
+    pub fn new(direction: Direction, constraints: Vec<Constraint>) -> Layout {
+        return Layout {
+            direction,
+            constraints,
+            horizontal_margin: 0,
+            vertical_margin: 0,
+            spacing: 0,
+        };
+    }
+
+    pub fn horizontal(constraints: Vec<Constraint>) -> Layout {
+        return Layout::new(Direction::Horizontal, constraints);
+    }
+
+    pub fn vertical(constraints: Vec<Constraint>) -> Layout {
+        return Layout::new(Direction::Vertical, constraints);
+    }
+
+    pub fn margin(mut self, margin: u16) -> Layout {
+        self.horizontal_margin = margin;
+        self.vertical_margin = margin;
+        return self;
+    }
+
+    pub fn horizontal_margin(mut self, margin: u16) -> Layout {
+        self.horizontal_margin = margin;
+        return self;
+    }
+
+    pub fn vertical_margin(mut self, margin: u16) -> Layout {
+        self.vertical_margin = margin;
+        return self;
+    }
+
+    pub fn spacing(mut self, spacing: u16) -> Layout {
+        self.spacing = spacing;
+        return self;
+    }
+
+    pub fn get_sizes(&self, total: u16) -> Vec<u16> {
+        let count = self.constraints.len() as u32;
+        let spacing = self.spacing as u32 * count.saturating_sub(1).min(u16::MAX as u32);
+        let total = (total as u32).saturating_sub(spacing) as u16;
+        let mut sizes: Vec<u16> = self
+            .constraints
+            .iter()
+            .map(|constraint| constraint.get_base(total))
+            .collect();
+        let used: u64 = sizes.iter().map(|&size| size as u64).sum();
+        let total = total as u64;
+        match used > total {
+            true => shrink(&mut sizes, &self.constraints, (used - total) as u32),
+            false => grow(&mut sizes, &self.constraints, (total - used) as u32),
+        }
+        return sizes;
+    }
+
+    pub fn split(&self, area: Rect) -> Vec<Rect> {
+        let width = area
+            .width
+            .saturating_sub(self.horizontal_margin.saturating_mul(2));
+        let height = area
+            .height
+            .saturating_sub(self.vertical_margin.saturating_mul(2));
+        let inner = Rect::new(
+            area.x.saturating_add((area.width - width) / 2),
+            area.y.saturating_add((area.height - height) / 2),
+            width,
+            height,
+        );
+        let total = match self.direction {
+            Direction::Horizontal => inner.width,
+            Direction::Vertical => inner.height,
+        };
+        let mut offset: u16 = 0;
+        let mut rects: Vec<Rect> = Vec::new();
+        for size in self.get_sizes(total) {
+            let size = size.min(total.saturating_sub(offset));
+            rects.push(match self.direction {
+                Direction::Horizontal => {
+                    Rect::new(inner.x.saturating_add(offset), inner.y, size, inner.height)
+                }
+                Direction::Vertical => {
+                    Rect::new(inner.x, inner.y.saturating_add(offset), inner.width, size)
+                }
+            });
+            offset = offset.saturating_add(size).saturating_add(self.spacing);
+        }
+        return rects;
+    }
+}
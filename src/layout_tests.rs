@@ -0,0 +1,106 @@
+use crate::{
+    layout::{Constraint, Layout},
+    rect::Rect,
+};
+
+#[test]
+fn test_layout_sizes() -> Result<(), String> {
+    let cases = [
+        (
+            vec![Constraint::Length(10), Constraint::Fill(1)],
+            100,
+            vec![10, 90],
+        ),
+        (
+            vec![Constraint::Percentage(25), Constraint::Percentage(75)],
+            80,
+            vec![20, 60],
+        ),
+        (
+            vec![Constraint::Ratio(1, 3), Constraint::Fill(1)],
+            90,
+            vec![30, 60],
+        ),
+        (
+            vec![
+                Constraint::Fill(1),
+                Constraint::Fill(2),
+                Constraint::Length(4),
+            ],
+            34,
+            vec![10, 20, 4],
+        ),
+        (
+            vec![Constraint::Max(10), Constraint::Min(5)],
+            50,
+            vec![10, 40],
+        ),
+        (
+            vec![Constraint::Min(30), Constraint::Length(30)],
+            40,
+            vec![30, 10],
+        ),
+        (
+            vec![Constraint::Length(3), Constraint::Length(3)],
+            4,
+            vec![3, 1],
+        ),
+        (
+            vec![Constraint::Length(60000), Constraint::Length(60000)],
+            100,
+            vec![100, 0],
+        ),
+        (
+            vec![Constraint::Fill(u16::MAX), Constraint::Fill(u16::MAX)],
+            u16::MAX,
+            vec![32768, 32767],
+        ),
+    ];
+    for (constraints, total, expected) in cases {
+        let sizes = Layout::horizontal(constraints.clone()).get_sizes(total);
+        if sizes != expected {
+            Err(format!(
+                "{:?} in {}: {:?} != {:?}",
+                constraints, total, sizes, expected
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_layout_split() -> Result<(), String> {
+    let layout = Layout::vertical(vec![
+        Constraint::Length(1),
+        Constraint::Fill(1),
+        Constraint::Length(1),
+    ])
+    .margin(1)
+    .spacing(1);
+    let rects = layout.split(Rect::new(1, 1, 20, 12));
+    let expected = vec![
+        Rect::new(2, 2, 18, 1),
+        Rect::new(2, 4, 18, 6),
+        Rect::new(2, 11, 18, 1),
+    ];
+    if rects != expected {
+        Err(format!("{:?} != {:?}", rects, expected))?;
+    }
+    let resized = layout.split(Rect::new(1, 1, 20, 8));
+    if resized[1] != Rect::new(2, 4, 18, 2) || resized[2] != Rect::new(2, 7, 18, 1) {
+        Err(format!("{:?}", resized))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_layout_split_saturates() -> Result<(), String> {
+    let layout = Layout::horizontal(vec![Constraint::Fill(1); 3]).spacing(u16::MAX);
+    let rects = layout.split(Rect::new(u16::MAX - 2, 1, 10, 1));
+    for rect in &rects {
+        if rect.x < u16::MAX - 2 || rect.width > 10 {
+            Err(format!("{:?}", rects))?;
+        }
+    }
+    return Ok(());
+}
@@ -6,13 +6,108 @@ use tui_enums::{Color, ThreeBool};
 
 pub mod font_settings;
 
+pub mod color;
+
+pub mod theme;
+
+pub mod rect;
+
+pub mod layout;
+
+pub mod region;
+
+pub mod image;
+
+pub mod canvas;
+
+pub mod widget;
+
+pub mod chart;
+
+pub mod progress;
+
+pub mod line_editor;
+
+pub mod text_area;
+
+pub mod list;
+
+pub mod box_drawing;
+
 pub mod tui_events;
 
 pub mod tui_terminal;
 
 pub mod tui_errors;
 
+pub mod tui_capabilities;
+
+pub mod text_wrap;
+
 mod tui_io;
 
+mod unicode_width;
+
+mod base64;
+
+mod png;
+
 #[cfg(test)]
 mod tui_terminal_tests;
+
+#[cfg(test)]
+mod string_plus_tests;
+
+#[cfg(test)]
+mod text_wrap_tests;
+
+#[cfg(test)]
+mod tui_capabilities_tests;
+
+#[cfg(test)]
+mod font_settings_tests;
+
+#[cfg(test)]
+mod color_tests;
+
+#[cfg(test)]
+mod reply_parser_tests;
+
+#[cfg(test)]
+mod theme_tests;
+
+#[cfg(test)]
+mod box_drawing_tests;
+
+#[cfg(test)]
+mod layout_tests;
+
+#[cfg(test)]
+mod region_tests;
+
+#[cfg(test)]
+mod tui_enums_tests;
+
+#[cfg(test)]
+mod base64_tests;
+
+#[cfg(test)]
+mod image_tests;
+
+#[cfg(test)]
+mod canvas_tests;
+
+#[cfg(test)]
+mod chart_tests;
+
+#[cfg(test)]
+mod progress_tests;
+
+#[cfg(test)]
+mod line_editor_tests;
+
+#[cfg(test)]
+mod text_area_tests;
+
+#[cfg(test)]
+mod list_tests;
@@ -0,0 +1,513 @@
+use std::{
+    error::Error,
+    fmt::Debug,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    string_plus::{StringPlus, StringPlusTrait},
+    theme::ThemeRole,
+    tui_enums::CursorNav,
+    tui_events::TuiEvents,
+    tui_terminal::TuiTerminal,
+    unicode_width::{grapheme_width, graphemes},
+};
+
+const DEFAULT_MAX_HISTORY: usize = 1000;
+
+pub trait Completer {
+    fn complete(&self, line: &str, cursor: usize) -> (usize, Vec<String>);
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LineEditorAction {
+    Continue,
+    Submit(String),
+    Cancel,
+    Eof,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct HistorySearch {
+    query: String,
+    match_index: Option<usize>,
+    is_failing: bool,
+    original_line: String,
+    original_cursor: usize,
+}
+
+pub struct LineEditor {
+    prompt: StringPlus,
+    buffer: String,
+    cursor: usize,
+    kill_buffer: String,
+    history: Vec<String>,
+    history_index: Option<usize>,
+    draft: String,
+    history_path: Option<PathBuf>,
+    max_history: usize,
+    search: Option<HistorySearch>,
+    completer: Option<Box<dyn Completer>>,
+    candidates: Vec<String>,
+}
+
+impl Debug for LineEditor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f
+            .debug_struct("LineEditor")
+            .field("prompt", &self.prompt)
+            .field("buffer", &self.buffer)
+            .field("cursor", &self.cursor)
+            .field("kill_buffer", &self.kill_buffer)
+            .field("history", &self.history)
+            .field("history_index", &self.history_index)
+            .field("draft", &self.draft)
+            .field("history_path", &self.history_path)
+            .field("max_history", &self.max_history)
+            .field("search", &self.search)
+            .field("has_completer", &self.completer.is_some())
+            .field("candidates", &self.candidates)
+            .finish();
+    }
+}
+
+fn is_word_character(grapheme: &str) -> bool {
+    return grapheme.chars().any(|c| c.is_alphanumeric() || c == '_');
+}
+
+impl LineEditor {
+    pub fn new<T: Into<StringPlus>>(prompt: T) -> LineEditor {
+        return LineEditor {
+            prompt: prompt.into(),
+            buffer: String::new(),
+            cursor: 0,
+            kill_buffer: String::new(),
+            history: Vec::new(),
+            history_index: None,
+            draft: String::new(),
+            history_path: None,
+            max_history: DEFAULT_MAX_HISTORY,
+            search: None,
+            completer: None,
+            candidates: Vec::new(),
+        };
+    }
+
+    pub fn completer(mut self, completer: Box<dyn Completer>) -> LineEditor {
+        self.completer = Some(completer);
+        return self;
+    }
+
+    pub fn max_history(mut self, max_history: usize) -> LineEditor {
+        self.max_history = max_history;
+        self.trim_history();
+        return self;
+    }
+
+    pub fn set_prompt<T: Into<StringPlus>>(&mut self, prompt: T) {
+        self.prompt = prompt.into();
+    }
+
+    pub fn get_line(&self) -> &str {
+        return &self.buffer;
+    }
+
+    pub fn set_line(&mut self, line: &str) {
+        self.buffer = line.to_string();
+        self.cursor = self.buffer.len();
+    }
+
+    pub fn get_cursor(&self) -> usize {
+        return self.cursor;
+    }
+
+    pub fn get_history(&self) -> &[String] {
+        return &self.history;
+    }
+
+    pub fn get_candidates(&self) -> &[String] {
+        return &self.candidates;
+    }
+
+    pub fn load_history(&mut self, path: &Path) -> Result<(), Box<dyn Error>> {
+        self.history_path = Some(path.to_path_buf());
+        if !path.exists() {
+            return Ok(());
+        }
+        self.history = fs::read_to_string(path)?
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+        self.trim_history();
+        return Ok(());
+    }
+
+    pub fn add_history(&mut self, line: &str) -> Result<(), Box<dyn Error>> {
+        if line.trim().is_empty() || self.history.last().map(String::as_str) == Some(line) {
+            return Ok(());
+        }
+        let line = line.replace('\n', " ");
+        self.history.push(line.clone());
+        self.trim_history();
+        if let Some(path) = &self.history_path {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            file.write_all((line + "\n").as_bytes())?;
+        }
+        return Ok(());
+    }
+
+    fn trim_history(&mut self) {
+        let excess = self.history.len().saturating_sub(self.max_history);
+        self.history.drain(..excess);
+    }
+
+    fn get_boundaries(&self) -> Vec<usize> {
+        let mut boundaries: Vec<usize> = vec![0];
+        for grapheme in graphemes(&self.buffer) {
+            boundaries.push(boundaries[boundaries.len() - 1] + grapheme.len());
+        }
+        return boundaries;
+    }
+
+    fn previous_boundary(&self) -> usize {
+        return self
+            .get_boundaries()
+            .into_iter()
+            .filter(|boundary| *boundary < self.cursor)
+            .max()
+            .unwrap_or(0);
+    }
+
+    fn next_boundary(&self) -> usize {
+        return self
+            .get_boundaries()
+            .into_iter()
+            .find(|boundary| *boundary > self.cursor)
+            .unwrap_or(self.buffer.len());
+    }
+
+    fn previous_word_boundary(&self, is_word: fn(&str) -> bool) -> usize {
+        let mut position = self.cursor;
+        let mut seen_word = false;
+        for grapheme in graphemes(&self.buffer[..self.cursor]).into_iter().rev() {
+            if is_word(grapheme) {
+                seen_word = true;
+            } else if seen_word {
+                break;
+            }
+            position -= grapheme.len();
+        }
+        return position;
+    }
+
+    fn next_word_boundary(&self) -> usize {
+        let mut position = self.cursor;
+        let mut seen_word = false;
+        for grapheme in graphemes(&self.buffer[self.cursor..]) {
+            if is_word_character(grapheme) {
+                seen_word = true;
+            } else if seen_word {
+                break;
+            }
+            position += grapheme.len();
+        }
+        return position;
+    }
+
+    fn insert(&mut self, text: &str) {
+        self.buffer.insert_str(self.cursor, text);
+        self.cursor += text.len();
+    }
+
+    fn kill(&mut self, start: usize, end: usize) {
+        if start >= end {
+            return;
+        }
+        self.kill_buffer = self.buffer[start..end].to_string();
+        self.buffer.replace_range(start..end, "");
+        self.cursor = start;
+    }
+
+    fn delete(&mut self, start: usize, end: usize) {
+        if start < end {
+            self.buffer.replace_range(start..end, "");
+            self.cursor = start;
+        }
+    }
+
+    fn recall_history(&mut self, index: Option<usize>) {
+        if self.history_index.is_none() {
+            self.draft = self.buffer.clone();
+        }
+        self.history_index = index;
+        let line = match index {
+            Some(index) => self.history[index].clone(),
+            None => self.draft.clone(),
+        };
+        self.set_line(&line);
+    }
+
+    fn history_previous(&mut self) {
+        match self.history_index {
+            Some(0) => {}
+            Some(index) => self.recall_history(Some(index - 1)),
+            None if !self.history.is_empty() => self.recall_history(Some(self.history.len() - 1)),
+            None => {}
+        }
+    }
+
+    fn history_next(&mut self) {
+        match self.history_index {
+            Some(index) if index + 1 < self.history.len() => self.recall_history(Some(index + 1)),
+            Some(_) => self.recall_history(None),
+            None => {}
+        }
+    }
+
+    fn complete(&mut self) {
+        let Some(completer) = &self.completer else {
+            return;
+        };
+        let (start, candidates) = completer.complete(&self.buffer, self.cursor);
+        let start = start.min(self.cursor);
+        if !self.buffer.is_char_boundary(start) || candidates.is_empty() {
+            return;
+        }
+        let mut prefix: String = candidates[0].clone();
+        for candidate in &candidates[1..] {
+            let common = prefix
+                .chars()
+                .zip(candidate.chars())
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a.len_utf8())
+                .sum();
+            prefix.truncate(common);
+        }
+        let word = self.buffer[start..self.cursor].to_string();
+        if candidates.len() == 1 {
+            self.delete(start, self.cursor);
+            self.insert(&(prefix + " "));
+        } else if prefix.len() > word.len() && prefix.starts_with(&word) {
+            self.delete(start, self.cursor);
+            self.insert(&prefix);
+        } else {
+            self.candidates = candidates;
+        }
+    }
+
+    fn find_history_match(&self, query: &str, before: usize) -> Option<usize> {
+        return self.history[..before.min(self.history.len())]
+            .iter()
+            .rposition(|line| line.contains(query));
+    }
+
+    fn start_search(&mut self) {
+        self.search = Some(HistorySearch {
+            query: String::new(),
+            match_index: None,
+            is_failing: false,
+            original_line: self.buffer.clone(),
+            original_cursor: self.cursor,
+        });
+    }
+
+    fn update_search(&mut self, query: String, before: usize) {
+        let match_index = self.find_history_match(&query, before);
+        if let Some(search) = &mut self.search {
+            search.is_failing = match_index.is_none() && !query.is_empty();
+            search.query = query;
+            if match_index.is_some() {
+                search.match_index = match_index;
+            }
+        }
+        if let Some(index) = match_index {
+            let line = self.history[index].clone();
+            let query = self.search.as_ref().map(|search| search.query.clone());
+            self.buffer = line;
+            self.cursor = match query.and_then(|query| self.buffer.find(&query)) {
+                Some(position) => position,
+                None => self.buffer.len(),
+            };
+        }
+    }
+
+    fn handle_search_event(&mut self, event: TuiEvents) -> Option<LineEditorAction> {
+        let search = self.search.clone()?;
+        match event {
+            TuiEvents::AsciiReadable(c) | TuiEvents::Other(c) if !c.is_control() => {
+                let before = search.match_index.map(|index| index + 1);
+                self.update_search(
+                    search.query + &c.to_string(),
+                    before.unwrap_or(self.history.len()),
+                );
+            }
+            TuiEvents::Space => {
+                let before = search.match_index.map(|index| index + 1);
+                self.update_search(search.query + " ", before.unwrap_or(self.history.len()));
+            }
+            TuiEvents::Backspace => {
+                let mut query = search.query;
+                query.pop();
+                self.update_search(query, self.history.len());
+            }
+            TuiEvents::Control('R') => {
+                let before = search.match_index.unwrap_or(self.history.len());
+                self.update_search(search.query, before);
+            }
+            TuiEvents::Escape | TuiEvents::Control('G') | TuiEvents::Control('C') => {
+                self.buffer = search.original_line;
+                self.cursor = search.original_cursor;
+                self.search = None;
+            }
+            _ => {
+                self.search = None;
+                return Some(self.handle_event(event));
+            }
+        }
+        return Some(LineEditorAction::Continue);
+    }
+
+    pub fn handle_event(&mut self, event: TuiEvents) -> LineEditorAction {
+        if let TuiEvents::Error | TuiEvents::Ignore = event.filter_keyboard_events() {
+            return LineEditorAction::Continue;
+        }
+        self.candidates.clear();
+        if let Some(action) = self.handle_search_event(event) {
+            return action;
+        }
+        match event {
+            TuiEvents::AsciiReadable(c) | TuiEvents::Other(c) if !c.is_control() => {
+                self.insert(&c.to_string())
+            }
+            TuiEvents::Space => self.insert(" "),
+            TuiEvents::Enter => {
+                let line = std::mem::take(&mut self.buffer);
+                self.cursor = 0;
+                self.history_index = None;
+                return LineEditorAction::Submit(line);
+            }
+            TuiEvents::Control('C') => {
+                self.set_line("");
+                self.history_index = None;
+                return LineEditorAction::Cancel;
+            }
+            TuiEvents::Control('D') if self.buffer.is_empty() => return LineEditorAction::Eof,
+            TuiEvents::InputClosed => return LineEditorAction::Eof,
+            TuiEvents::Backspace | TuiEvents::Control('H') => {
+                self.delete(self.previous_boundary(), self.cursor)
+            }
+            TuiEvents::Delete | TuiEvents::Control('D') => {
+                let cursor = self.cursor;
+                self.delete(cursor, self.next_boundary());
+            }
+            TuiEvents::LeftArrow | TuiEvents::Control('B') => {
+                self.cursor = self.previous_boundary()
+            }
+            TuiEvents::RightArrow | TuiEvents::Control('F') => self.cursor = self.next_boundary(),
+            TuiEvents::Home | TuiEvents::Control('A') => self.cursor = 0,
+            TuiEvents::End | TuiEvents::Control('E') => self.cursor = self.buffer.len(),
+            TuiEvents::Alt('b') => self.cursor = self.previous_word_boundary(is_word_character),
+            TuiEvents::Alt('f') => self.cursor = self.next_word_boundary(),
+            TuiEvents::Alt('d') => self.kill(self.cursor, self.next_word_boundary()),
+            TuiEvents::Control('K') => self.kill(self.cursor, self.buffer.len()),
+            TuiEvents::Control('U') => self.kill(0, self.cursor),
+            TuiEvents::Control('W') => self.kill(
+                self.previous_word_boundary(|grapheme| !grapheme.trim().is_empty()),
+                self.cursor,
+            ),
+            TuiEvents::Control('Y') => {
+                let text = self.kill_buffer.clone();
+                self.insert(&text);
+            }
+            TuiEvents::UpArrow | TuiEvents::Control('P') => self.history_previous(),
+            TuiEvents::DownArrow | TuiEvents::Control('N') => self.history_next(),
+            TuiEvents::Control('R') => self.start_search(),
+            TuiEvents::Tab => self.complete(),
+            _ => {}
+        }
+        return LineEditorAction::Continue;
+    }
+
+    fn get_prompt(&self) -> StringPlus {
+        return match &self.search {
+            Some(search) => {
+                let status = match search.is_failing {
+                    true => "failing ",
+                    false => "",
+                };
+                ("(".to_string() + status + "reverse-i-search)`" + &search.query + "': ")
+                    .set_role(ThemeRole::Muted)
+            }
+            None => self.prompt.clone(),
+        };
+    }
+
+    pub fn render(&self, width: u16) -> (Vec<StringPlus>, u16) {
+        let prompt = self.get_prompt().truncate(width.saturating_sub(1) as usize);
+        let available = (width as usize).saturating_sub(prompt.width()).max(1);
+        let cells: Vec<(&str, usize)> = graphemes(&self.buffer)
+            .into_iter()
+            .map(|grapheme| (grapheme, grapheme_width(grapheme)))
+            .collect();
+        let mut cursor_column: usize = 0;
+        let mut position: usize = 0;
+        for (grapheme, width) in &cells {
+            if position >= self.cursor {
+                break;
+            }
+            position += grapheme.len();
+            cursor_column += width;
+        }
+        let offset = (cursor_column + 1).saturating_sub(available);
+        let mut column: usize = 0;
+        let mut visible: String = String::new();
+        for (grapheme, width) in cells {
+            if column >= offset && column + width <= offset + available {
+                visible += grapheme;
+            }
+            column += width;
+        }
+        let cursor = (prompt.width() + cursor_column - offset + 1) as u16;
+        return (vec![prompt, visible.into()], cursor);
+    }
+
+    pub fn draw(&self, tui_terminal: &mut TuiTerminal) {
+        let width = match tui_terminal.get_teminal_size() {
+            Ok((width, _)) if width > 0 => width,
+            _ => 80,
+        };
+        let (line, cursor) = self.render(width);
+        tui_terminal.shift_cursor(CursorNav::Column(1));
+        tui_terminal.clear_line();
+        for span in line {
+            tui_terminal.write(span);
+        }
+        tui_terminal.shift_cursor(CursorNav::Column(cursor));
+    }
+
+    pub fn read_line(&mut self, tui_terminal: &mut TuiTerminal) -> LineEditorAction {
+        loop {
+            self.draw(tui_terminal);
+            let action = self.handle_event(tui_terminal.get_event());
+            if !self.candidates.is_empty() {
+                tui_terminal.println("");
+                tui_terminal.shift_cursor(CursorNav::Column(1));
+                tui_terminal.println(self.candidates.join("  "));
+            }
+            if let LineEditorAction::Continue = action {
+                continue;
+            }
+            if let LineEditorAction::Submit(line) = &action {
+                _ = self.add_history(line);
+                self.set_line(line);
+                self.draw(tui_terminal);
+                self.set_line("");
+            }
+            tui_terminal.println("");
+            return action;
+        }
+    }
+}
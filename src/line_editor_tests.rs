@@ -0,0 +1,226 @@
+use std::{env, fs, process};
+
+use crate::{
+    line_editor::{Completer, LineEditor, LineEditorAction},
+    tui_events::TuiEvents,
+    tui_io::input_parser::parse_sequence,
+};
+
+struct WordCompleter {
+    words: Vec<&'static str>,
+}
+
+impl Completer for WordCompleter {
+    fn complete(&self, line: &str, cursor: usize) -> (usize, Vec<String>) {
+        let start = line[..cursor]
+            .rfind(' ')
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let word = &line[start..cursor];
+        let candidates = self
+            .words
+            .iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .map(|candidate| candidate.to_string())
+            .collect();
+        return (start, candidates);
+    }
+}
+
+fn type_sequence(editor: &mut LineEditor, sequence: &str) -> LineEditorAction {
+    let mut action = LineEditorAction::Continue;
+    for event in parse_sequence(sequence, true) {
+        action = editor.handle_event(event);
+    }
+    return action;
+}
+
+#[test]
+fn test_parse_editing_keys() -> Result<(), String> {
+    let events = parse_sequence(
+        "\x1bb\x1b[H\x1b[4~\x1bOF\u{c3}\u{a9}\u{e2}\u{82}\u{ac}",
+        true,
+    );
+    let expected = vec![
+        TuiEvents::Alt('b'),
+        TuiEvents::Home,
+        TuiEvents::End,
+        TuiEvents::End,
+        TuiEvents::Other('é'),
+        TuiEvents::Other('€'),
+    ];
+    if events != expected {
+        Err(format!("{:?} != {:?}", events, expected))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_emacs_bindings() -> Result<(), String> {
+    let mut editor = LineEditor::new("> ");
+    type_sequence(&mut editor, "git commit --amend\x01\x1bf\x1bf\x0b");
+    if editor.get_line() != "git commit" {
+        Err(format!("Ctrl+K after Alt+F: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "\x1bb\x17\x05 \x19");
+    if editor.get_line() != "commit git " {
+        Err(format!("Ctrl+W and Ctrl+Y: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "\x15");
+    if editor.get_line() != "" || editor.get_cursor() != 0 {
+        Err(format!("Ctrl+U: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "n\u{c3}\u{a9}e\x1b[D\x7f\x1b[3~");
+    if editor.get_line() != "n" {
+        Err(format!(
+            "Unicode backspace and delete: {:?}",
+            editor.get_line()
+        ))?;
+    }
+    if type_sequence(&mut editor, "\r") != LineEditorAction::Submit("n".to_string()) {
+        Err("Enter did not submit")?;
+    }
+    if type_sequence(&mut editor, "\x04") != LineEditorAction::Eof {
+        Err("Ctrl+D on an empty line did not signal end of input")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_history_and_search() -> Result<(), String> {
+    let path = env::temp_dir().join(format!("tui_lib_history_{}", process::id()));
+    _ = fs::remove_file(&path);
+    let mut editor = LineEditor::new("> ");
+    editor
+        .load_history(&path)
+        .map_err(|error| error.to_string())?;
+    for line in ["make build", "cargo test", "make clean", "make clean"] {
+        editor
+            .add_history(line)
+            .map_err(|error| error.to_string())?;
+    }
+    let mut editor = LineEditor::new("> ");
+    editor
+        .load_history(&path)
+        .map_err(|error| error.to_string())?;
+    _ = fs::remove_file(&path);
+    if editor.get_history() != ["make build", "cargo test", "make clean"] {
+        Err(format!("History not persisted: {:?}", editor.get_history()))?;
+    }
+    type_sequence(&mut editor, "dra\x1b[A\x1b[A");
+    if editor.get_line() != "cargo test" {
+        Err(format!("Up arrow: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "\x1b[B\x1b[B");
+    if editor.get_line() != "dra" {
+        Err(format!("Draft not restored: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "\x12make\x12");
+    if editor.get_line() != "make build" {
+        Err(format!("Ctrl+R: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "\x07");
+    if editor.get_line() != "dra" {
+        Err(format!(
+            "Ctrl+G did not cancel the search: {:?}",
+            editor.get_line()
+        ))?;
+    }
+    let action = type_sequence(&mut editor, "\x12test\r");
+    if action != LineEditorAction::Submit("cargo test".to_string()) {
+        Err(format!("Search result not submitted: {:?}", action))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_completion() -> Result<(), String> {
+    let mut editor = LineEditor::new("> ").completer(Box::new(WordCompleter {
+        words: vec!["checkout", "cherry-pick", "commit"],
+    }));
+    type_sequence(&mut editor, "git ch\t");
+    if editor.get_line() != "git che" || !editor.get_candidates().is_empty() {
+        Err(format!("Common prefix: {:?}", editor.get_line()))?;
+    }
+    type_sequence(&mut editor, "\t");
+    if editor.get_candidates() != ["checkout", "cherry-pick"] {
+        Err(format!("Candidates: {:?}", editor.get_candidates()))?;
+    }
+    type_sequence(&mut editor, "c\t");
+    if editor.get_line() != "git checkout " {
+        Err(format!("Single completion: {:?}", editor.get_line()))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_render_scrolls_horizontally() -> Result<(), String> {
+    let mut editor = LineEditor::new("> ");
+    editor.set_line("0123456789漢字");
+    let (line, cursor) = editor.render(10);
+    let text: String = line.iter().map(|span| span.to_string()).collect();
+    if text != "> 789漢字" || cursor != 10 {
+        Err(format!("{:?} with cursor at {}", text, cursor))?;
+    }
+    type_sequence(&mut editor, "\x01");
+    let (line, cursor) = editor.render(10);
+    let text: String = line.iter().map(|span| span.to_string()).collect();
+    if text != "> 01234567" || cursor != 3 {
+        Err(format!("{:?} with cursor at {}", text, cursor))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_unhandled_input_keeps_buffer() -> Result<(), String> {
+    let mut editor = LineEditor::new("> ");
+    type_sequence(&mut editor, "ab");
+    for sequence in [
+        "\x1b[<35;10;5M",
+        "\x1b[1;5C",
+        "\x1bOP",
+        "\x1b[Z",
+        "\x1b[99~",
+        "\u{e9}",
+    ] {
+        let action = type_sequence(&mut editor, sequence);
+        if action != LineEditorAction::Continue || editor.get_line() != "ab" {
+            Err(format!(
+                "{:?}: {:?} {:?}",
+                sequence,
+                action,
+                editor.get_line()
+            ))?;
+        }
+    }
+    type_sequence(&mut editor, "c");
+    if editor.get_line() != "abc" || editor.get_cursor() != 3 {
+        Err(format!("{:?} {}", editor.get_line(), editor.get_cursor()))?;
+    }
+    if editor.handle_event(TuiEvents::InputClosed) != LineEditorAction::Eof {
+        Err("Closed input did not end the line editor")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_utf8_decoding_only_for_byte_input() -> Result<(), String> {
+    let cases = [
+        ("\u{c3}\u{a9}", true, vec![TuiEvents::Other('é')]),
+        ("\u{e9}", true, vec![TuiEvents::Error]),
+        ("é", false, vec![TuiEvents::Other('é')]),
+        (
+            "Âx",
+            false,
+            vec![TuiEvents::Other('Â'), TuiEvents::AsciiReadable('x')],
+        ),
+        ("漢", false, vec![TuiEvents::Other('漢')]),
+    ];
+    for (sequence, is_utf8_input, expected) in cases {
+        let events = parse_sequence(sequence, is_utf8_input);
+        if events != expected {
+            Err(format!("{:?} ({}): {:?}", sequence, is_utf8_input, events))?;
+        }
+    }
+    return Ok(());
+}
@@ -0,0 +1,288 @@
+use std::{
+    collections::BTreeSet,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    font_settings::FontSettings,
+    rect::Rect,
+    string_plus::{StringPlus, StringPlusTrait},
+    theme::ThemeRole,
+    tui_events::TuiEvents,
+    tui_terminal::TuiTerminal,
+    widget::Widget,
+};
+
+const SCROLL_LINES: usize = 3;
+const TYPE_TO_JUMP_TIMEOUT: Duration = Duration::from_millis(1000);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelectionMode {
+    Single,
+    Multiple,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListAction {
+    Ignored,
+    Changed,
+    Activated(usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct List {
+    items: Vec<StringPlus>,
+    selected: usize,
+    checked: BTreeSet<usize>,
+    selection_mode: SelectionMode,
+    highlight: Option<FontSettings>,
+    highlight_symbol: String,
+    offset: usize,
+    rect: Option<Rect>,
+    jump_prefix: String,
+    last_jump: Option<Instant>,
+}
+
+impl List {
+    pub fn new(items: Vec<StringPlus>) -> List {
+        return List {
+            items,
+            selected: 0,
+            checked: BTreeSet::new(),
+            selection_mode: SelectionMode::Single,
+            highlight: None,
+            highlight_symbol: String::new(),
+            offset: 0,
+            rect: None,
+            jump_prefix: String::new(),
+            last_jump: None,
+        };
+    }
+
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> List {
+        self.selection_mode = selection_mode;
+        return self;
+    }
+
+    pub fn highlight(mut self, font_settings: FontSettings) -> List {
+        self.highlight = Some(font_settings);
+        return self;
+    }
+
+    pub fn highlight_symbol(mut self, symbol: &str) -> List {
+        self.highlight_symbol = symbol.to_string();
+        return self;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.items.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.items.is_empty();
+    }
+
+    pub fn get_items(&self) -> &[StringPlus] {
+        return &self.items;
+    }
+
+    pub fn set_items(&mut self, items: Vec<StringPlus>) {
+        self.items = items;
+        self.checked.retain(|index| *index < self.items.len());
+        self.select(self.selected);
+    }
+
+    pub fn get_selected(&self) -> Option<usize> {
+        if self.items.is_empty() {
+            return None;
+        }
+        return Some(self.selected);
+    }
+
+    pub fn set_selected(&mut self, index: usize) {
+        self.select(index);
+    }
+
+    pub fn get_checked(&self) -> Vec<usize> {
+        return self.checked.iter().copied().collect();
+    }
+
+    pub fn set_checked(&mut self, index: usize, is_checked: bool) {
+        if self.selection_mode != SelectionMode::Multiple || index >= self.items.len() {
+            return;
+        }
+        match is_checked {
+            true => self.checked.insert(index),
+            false => self.checked.remove(&index),
+        };
+    }
+
+    pub fn get_offset(&self) -> usize {
+        return self.offset;
+    }
+
+    pub fn set_rect(&mut self, rect: Rect) {
+        self.rect = Some(rect);
+        self.ensure_selected_visible();
+    }
+
+    fn get_height(&self) -> usize {
+        return match self.rect {
+            Some(rect) => rect.height.max(1) as usize,
+            None => self.items.len().max(1),
+        };
+    }
+
+    fn ensure_selected_visible(&mut self) {
+        let height = self.get_height();
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + height {
+            self.offset = self.selected + 1 - height;
+        }
+        self.offset = self.offset.min(self.items.len().saturating_sub(height));
+    }
+
+    fn select(&mut self, index: usize) {
+        self.selected = index.min(self.items.len().saturating_sub(1));
+        self.ensure_selected_visible();
+    }
+
+    fn move_selection(&mut self, offset: isize) -> ListAction {
+        let previous = self.selected;
+        self.select(self.selected.saturating_add_signed(offset));
+        return match self.selected == previous {
+            true => ListAction::Ignored,
+            false => ListAction::Changed,
+        };
+    }
+
+    fn jump_to_prefix(&mut self, c: char) -> ListAction {
+        let now = Instant::now();
+        let is_continuing = self
+            .last_jump
+            .is_some_and(|last_jump| now.duration_since(last_jump) < TYPE_TO_JUMP_TIMEOUT);
+        if !is_continuing {
+            self.jump_prefix.clear();
+        }
+        self.last_jump = Some(now);
+        self.jump_prefix.extend(c.to_lowercase());
+        let start = match self.jump_prefix.chars().count() {
+            1 => self.selected + 1,
+            _ => self.selected,
+        };
+        let length = self.items.len();
+        for step in 0..length {
+            let index = (start + step) % length;
+            if self.items[index]
+                .to_string()
+                .to_lowercase()
+                .starts_with(&self.jump_prefix)
+            {
+                self.select(index);
+                return ListAction::Changed;
+            }
+        }
+        return ListAction::Ignored;
+    }
+
+    fn toggle_selected(&mut self) -> ListAction {
+        if self.selection_mode != SelectionMode::Multiple || self.items.is_empty() {
+            return ListAction::Ignored;
+        }
+        let is_checked = !self.checked.contains(&self.selected);
+        self.set_checked(self.selected, is_checked);
+        return ListAction::Changed;
+    }
+
+    pub fn handle_event(&mut self, event: TuiEvents) -> ListAction {
+        if self.items.is_empty() {
+            return ListAction::Ignored;
+        }
+        let height = self.get_height() as isize;
+        return match event {
+            TuiEvents::UpArrow => self.move_selection(-1),
+            TuiEvents::DownArrow => self.move_selection(1),
+            TuiEvents::PageUp => self.move_selection(-height),
+            TuiEvents::PageDown => self.move_selection(height),
+            TuiEvents::Home => self.move_selection(isize::MIN),
+            TuiEvents::End => self.move_selection(isize::MAX),
+            TuiEvents::Enter => ListAction::Activated(self.selected),
+            TuiEvents::Space => self.toggle_selected(),
+            TuiEvents::AsciiReadable(c) | TuiEvents::Other(c) if !c.is_control() => {
+                self.jump_to_prefix(c)
+            }
+            TuiEvents::LeftClick((x, y)) => match self.rect {
+                Some(rect) if rect.contains(x, y) => {
+                    let index = self.offset + (y - rect.y) as usize;
+                    if index >= self.items.len() {
+                        return ListAction::Ignored;
+                    }
+                    if index == self.selected {
+                        return match self.selection_mode {
+                            SelectionMode::Single => ListAction::Activated(index),
+                            SelectionMode::Multiple => self.toggle_selected(),
+                        };
+                    }
+                    self.select(index);
+                    ListAction::Changed
+                }
+                _ => ListAction::Ignored,
+            },
+            TuiEvents::ScrollUp((x, y)) | TuiEvents::ScrollDown((x, y))
+                if self.rect.is_some_and(|rect| rect.contains(x, y)) =>
+            {
+                let max_offset = self.items.len().saturating_sub(self.get_height());
+                self.offset = match event {
+                    TuiEvents::ScrollUp(_) => self.offset.saturating_sub(SCROLL_LINES),
+                    _ => (self.offset + SCROLL_LINES).min(max_offset),
+                };
+                ListAction::Changed
+            }
+            _ => ListAction::Ignored,
+        };
+    }
+
+    pub fn draw(&mut self, tui_terminal: &mut TuiTerminal, rect: Rect) {
+        self.set_rect(rect);
+        tui_terminal.draw_widget(self, rect);
+    }
+}
+
+impl Widget for List {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>> {
+        let width = width as usize;
+        let symbol_width = StringPlus::from(self.highlight_symbol.as_str()).width();
+        return (self.offset..self.items.len())
+            .take(height as usize)
+            .map(|index| {
+                let is_selected = index == self.selected;
+                let mut prefix = match is_selected {
+                    true => self.highlight_symbol.clone(),
+                    false => " ".repeat(symbol_width),
+                };
+                if self.selection_mode == SelectionMode::Multiple {
+                    prefix += match self.checked.contains(&index) {
+                        true => "[x] ",
+                        false => "[ ] ",
+                    };
+                }
+                let prefix = StringPlus::from(prefix).truncate(width);
+                let item = self.items[index].truncate(width - prefix.width());
+                let padding = " ".repeat(width - prefix.width() - item.width());
+                let mut spans = vec![prefix, item, padding.into()];
+                if is_selected {
+                    spans = spans
+                        .into_iter()
+                        .map(|span| match self.highlight {
+                            Some(font_settings) => span.set_font_settings(font_settings),
+                            None => span.set_role(ThemeRole::Selection),
+                        })
+                        .collect();
+                }
+                spans.retain(|span| !span.to_string().is_empty());
+                spans
+            })
+            .collect();
+    }
+}
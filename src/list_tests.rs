@@ -0,0 +1,150 @@
+use std::time::Instant;
+
+use crate::{
+    font_settings::FontSettings,
+    list::{List, ListAction, SelectionMode},
+    rect::Rect,
+    string_plus::StringPlus,
+    tui_enums::{Color, ThreeBool},
+    tui_events::TuiEvents,
+    tui_io::input_parser::parse_sequence,
+    widget::Widget,
+};
+
+fn fruit_list() -> List {
+    return List::new(
+        [
+            "apple",
+            "banana",
+            "blueberry",
+            "cherry",
+            "date",
+            "elderberry",
+        ]
+        .map(StringPlus::from)
+        .to_vec(),
+    );
+}
+
+fn to_text(lines: &[Vec<StringPlus>]) -> Vec<String> {
+    return lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.to_string()).collect())
+        .collect();
+}
+
+#[test]
+fn test_keyboard_navigation() -> Result<(), String> {
+    let mut list = fruit_list();
+    list.set_rect(Rect::new(1, 1, 10, 3));
+    for event in parse_sequence("\x1b[B\x1b[6~", true) {
+        list.handle_event(event);
+    }
+    if list.get_selected() != Some(4) || list.get_offset() != 2 {
+        Err(format!(
+            "{:?} at offset {}",
+            list.get_selected(),
+            list.get_offset()
+        ))?;
+    }
+    list.handle_event(TuiEvents::End);
+    list.handle_event(TuiEvents::DownArrow);
+    if list.get_selected() != Some(5) || list.get_offset() != 3 {
+        Err(format!("End: {:?}", list.get_selected()))?;
+    }
+    list.handle_event(TuiEvents::Home);
+    if list.get_selected() != Some(0) || list.get_offset() != 0 {
+        Err(format!("Home: {:?}", list.get_selected()))?;
+    }
+    if list.handle_event(TuiEvents::Enter) != ListAction::Activated(0) {
+        Err("Enter did not activate")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_type_to_jump() -> Result<(), String> {
+    let mut list = fruit_list();
+    list.handle_event(TuiEvents::AsciiReadable('b'));
+    if list.get_selected() != Some(1) {
+        Err(format!("'b': {:?}", list.get_selected()))?;
+    }
+    list.handle_event(TuiEvents::AsciiReadable('l'));
+    if list.get_selected() != Some(2) {
+        Err(format!("'bl': {:?}", list.get_selected()))?;
+    }
+    if list.handle_event(TuiEvents::AsciiReadable('z')) != ListAction::Ignored {
+        Err("Jumped without a match")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_mouse_and_multi_select() -> Result<(), String> {
+    let mut list = fruit_list().selection_mode(SelectionMode::Multiple);
+    list.set_rect(Rect::new(3, 5, 12, 3));
+    list.handle_event(TuiEvents::LeftClick((4, 6)));
+    list.handle_event(TuiEvents::LeftClick((4, 6)));
+    list.handle_event(TuiEvents::Space);
+    list.handle_event(TuiEvents::DownArrow);
+    list.handle_event(TuiEvents::Space);
+    if list.get_checked() != [2] {
+        Err(format!("{:?}", list.get_checked()))?;
+    }
+    list.handle_event(TuiEvents::ScrollDown((4, 6)));
+    if list.get_offset() != 3 || list.get_selected() != Some(2) {
+        Err(format!("Scroll wheel: offset {}", list.get_offset()))?;
+    }
+    list.handle_event(TuiEvents::ScrollUp((1, 1)));
+    if list.get_offset() != 3 {
+        Err("Scrolled from outside the list")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_render_highlight() -> Result<(), String> {
+    let highlight = FontSettings {
+        font_color: Color::Black,
+        background_color: Color::Cyan,
+        is_bold: ThreeBool::True,
+        ..FontSettings::default()
+    };
+    let mut list = fruit_list().highlight(highlight).highlight_symbol("> ");
+    list.set_selected(1);
+    let lines = list.render(9, 3);
+    if to_text(&lines) != ["  apple  ", "> banana ", "  bluebe…"] {
+        Err(format!("{:?}", to_text(&lines)))?;
+    }
+    if lines[1]
+        .iter()
+        .any(|span| *span.get_font_settings() != highlight)
+        || *lines[0][1].get_font_settings() == highlight
+    {
+        Err(format!("Highlight not applied: {:?}", lines[1]))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_virtualized_render() -> Result<(), String> {
+    let mut list = List::new(
+        (0..100_000)
+            .map(|index| StringPlus::from(format!("item {}", index)))
+            .collect(),
+    );
+    list.set_rect(Rect::new(1, 1, 20, 10));
+    let start = Instant::now();
+    for _ in 0..1000 {
+        list.handle_event(TuiEvents::PageDown);
+        list.render(20, 10);
+    }
+    let lines = to_text(&list.render(20, 10));
+    if lines.len() != 10 || !lines[9].starts_with("item 10000") {
+        Err(format!("{:?}", lines))?;
+    }
+    if start.elapsed().as_secs() >= 2 {
+        Err(format!("Rendering took {:?}", start.elapsed()))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,76 @@
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const MAX_STORED_BLOCK: usize = 0xffff;
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc: u32 = 0xffff_ffff;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    return !crc;
+}
+
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b): (u32, u32) = (1, 0);
+    for chunk in bytes.chunks(5552) {
+        for byte in chunk {
+            a += *byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    return (b << 16) | a;
+}
+
+fn zlib_stored(bytes: &[u8]) -> Vec<u8> {
+    let mut output: Vec<u8> = vec![0x78, 0x01];
+    let mut blocks = bytes.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        output.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_last = blocks.peek().is_none();
+        let length = block.len() as u16;
+        output.push(is_last as u8);
+        output.extend_from_slice(&length.to_le_bytes());
+        output.extend_from_slice(&(!length).to_le_bytes());
+        output.extend_from_slice(block);
+    }
+    output.extend_from_slice(&adler32(bytes).to_be_bytes());
+    return output;
+}
+
+fn push_chunk(output: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = output.len();
+    output.extend_from_slice(kind);
+    output.extend_from_slice(data);
+    let crc = crc32(&output[start..]);
+    output.extend_from_slice(&crc.to_be_bytes());
+}
+
+pub fn encode_rgba(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    let mut header: Vec<u8> = Vec::new();
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+    let mut scanlines: Vec<u8> = Vec::with_capacity(pixels.len() + height as usize);
+    for row in pixels
+        .chunks((width as usize * 4).max(1))
+        .take(height as usize)
+    {
+        scanlines.push(0);
+        scanlines.extend_from_slice(row);
+    }
+    let mut output: Vec<u8> = SIGNATURE.to_vec();
+    push_chunk(&mut output, b"IHDR", &header);
+    push_chunk(&mut output, b"IDAT", &zlib_stored(&scanlines));
+    push_chunk(&mut output, b"IEND", &[]);
+    return output;
+}
@@ -0,0 +1,422 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    chart::format_value,
+    string_plus::StringPlus,
+    tui_enums::CursorNav,
+    tui_terminal::TuiTerminal,
+    widget::{merge_cells, Widget},
+};
+
+const DEFAULT_BAR_TEMPLATE: &str = "{msg} {bar} {pos}/{len} ({percent}%) {rate}/s ETA {eta}";
+const DEFAULT_SPINNER_TEMPLATE: &str = "{spinner} {msg} [{elapsed}]";
+const DEFAULT_SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+const DEFAULT_TERMINAL_SIZE: (u16, u16) = (80, 24);
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    return mutex.lock().unwrap_or_else(|error| error.into_inner());
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64().round() as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        return format!("{}:{:02}:{:02}", hours, minutes, seconds);
+    }
+    return format!("{:02}:{:02}", minutes, seconds);
+}
+
+#[derive(Clone, Debug)]
+pub struct ProgressStyle {
+    template: Vec<StringPlus>,
+    filled: char,
+    empty: char,
+    spinner_frames: Vec<String>,
+    finished_frame: String,
+}
+
+impl ProgressStyle {
+    pub fn default_bar() -> ProgressStyle {
+        return ProgressStyle {
+            template: vec![DEFAULT_BAR_TEMPLATE.into()],
+            filled: '█',
+            empty: '░',
+            spinner_frames: DEFAULT_SPINNER_FRAMES.map(String::from).to_vec(),
+            finished_frame: "✔".to_string(),
+        };
+    }
+
+    pub fn default_spinner() -> ProgressStyle {
+        return ProgressStyle {
+            template: vec![DEFAULT_SPINNER_TEMPLATE.into()],
+            ..ProgressStyle::default_bar()
+        };
+    }
+
+    pub fn template(mut self, template: Vec<StringPlus>) -> ProgressStyle {
+        self.template = template;
+        return self;
+    }
+
+    pub fn progress_characters(mut self, filled: char, empty: char) -> ProgressStyle {
+        self.filled = filled;
+        self.empty = empty;
+        return self;
+    }
+
+    pub fn spinner_frames(mut self, frames: &[&str], finished_frame: &str) -> ProgressStyle {
+        if !frames.is_empty() {
+            self.spinner_frames = frames.iter().map(|frame| frame.to_string()).collect();
+        }
+        self.finished_frame = finished_frame.to_string();
+        return self;
+    }
+}
+
+#[derive(Debug)]
+struct ProgressState {
+    position: u64,
+    length: Option<u64>,
+    message: String,
+    style: ProgressStyle,
+    start: Instant,
+    finished_at: Option<Instant>,
+}
+
+impl ProgressState {
+    fn get_elapsed(&self, now: Instant) -> Duration {
+        return self
+            .finished_at
+            .unwrap_or(now)
+            .saturating_duration_since(self.start);
+    }
+
+    fn get_rate(&self, now: Instant) -> f64 {
+        let seconds = self.get_elapsed(now).as_secs_f64();
+        if seconds <= 0.0 {
+            return 0.0;
+        }
+        return self.position as f64 / seconds;
+    }
+
+    fn get_eta(&self, now: Instant) -> Option<Duration> {
+        if self.finished_at.is_some() {
+            return Some(Duration::ZERO);
+        }
+        let rate = self.get_rate(now);
+        let length = self.length?;
+        if rate <= 0.0 {
+            return None;
+        }
+        return Some(Duration::from_secs_f64(
+            length.saturating_sub(self.position) as f64 / rate,
+        ));
+    }
+
+    fn get_value(&self, key: &str, now: Instant) -> Option<String> {
+        return Some(match key {
+            "msg" => self.message.clone(),
+            "pos" => self.position.to_string(),
+            "len" => match self.length {
+                Some(length) => length.to_string(),
+                None => "?".to_string(),
+            },
+            "percent" => match self.length {
+                Some(0) => "100".to_string(),
+                Some(length) => (self.position.min(length) * 100 / length).to_string(),
+                None => "?".to_string(),
+            },
+            "rate" => format_value(self.get_rate(now)),
+            "eta" => match self.get_eta(now) {
+                Some(eta) => format_duration(eta),
+                None => "--:--".to_string(),
+            },
+            "elapsed" => format_duration(self.get_elapsed(now)),
+            "spinner" => match self.finished_at {
+                Some(_) => self.style.finished_frame.clone(),
+                None => {
+                    let frames = &self.style.spinner_frames;
+                    let frame = self.get_elapsed(now).as_millis() / SPINNER_INTERVAL.as_millis();
+                    frames[frame as usize % frames.len()].clone()
+                }
+            },
+            _ => return None,
+        });
+    }
+
+    fn get_bar(&self, width: usize) -> String {
+        let filled = match self.length {
+            Some(0) => width,
+            Some(length) => {
+                (self.position.min(length) as f64 / length as f64 * width as f64).round() as usize
+            }
+            None => 0,
+        };
+        return self.style.filled.to_string().repeat(filled)
+            + &self.style.empty.to_string().repeat(width - filled);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ProgressBar {
+    state: Arc<Mutex<ProgressState>>,
+}
+
+impl ProgressBar {
+    fn with_length(length: Option<u64>, style: ProgressStyle) -> ProgressBar {
+        return ProgressBar {
+            state: Arc::new(Mutex::new(ProgressState {
+                position: 0,
+                length,
+                message: String::new(),
+                style,
+                start: Instant::now(),
+                finished_at: None,
+            })),
+        };
+    }
+
+    pub fn new(length: u64) -> ProgressBar {
+        return ProgressBar::with_length(Some(length), ProgressStyle::default_bar());
+    }
+
+    pub fn spinner() -> ProgressBar {
+        return ProgressBar::with_length(None, ProgressStyle::default_spinner());
+    }
+
+    pub fn style(self, style: ProgressStyle) -> ProgressBar {
+        self.set_style(style);
+        return self;
+    }
+
+    pub fn message(self, message: &str) -> ProgressBar {
+        self.set_message(message);
+        return self;
+    }
+
+    pub fn set_style(&self, style: ProgressStyle) {
+        lock(&self.state).style = style;
+    }
+
+    pub fn set_message(&self, message: &str) {
+        lock(&self.state).message = message.to_string();
+    }
+
+    pub fn get_message(&self) -> String {
+        return lock(&self.state).message.clone();
+    }
+
+    pub fn inc(&self, delta: u64) {
+        let mut state = lock(&self.state);
+        state.position = state.position.saturating_add(delta);
+    }
+
+    pub fn set_position(&self, position: u64) {
+        lock(&self.state).position = position;
+    }
+
+    pub fn get_position(&self) -> u64 {
+        return lock(&self.state).position;
+    }
+
+    pub fn set_length(&self, length: u64) {
+        lock(&self.state).length = Some(length);
+    }
+
+    pub fn get_length(&self) -> Option<u64> {
+        return lock(&self.state).length;
+    }
+
+    pub fn reset(&self) {
+        let mut state = lock(&self.state);
+        state.position = 0;
+        state.start = Instant::now();
+        state.finished_at = None;
+    }
+
+    pub fn finish(&self) {
+        let mut state = lock(&self.state);
+        if let Some(length) = state.length {
+            state.position = state.position.max(length);
+        }
+        if state.finished_at.is_none() {
+            state.finished_at = Some(Instant::now());
+        }
+    }
+
+    pub fn finish_with_message(&self, message: &str) {
+        self.set_message(message);
+        self.finish();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        return lock(&self.state).finished_at.is_some();
+    }
+
+    pub fn get_elapsed(&self) -> Duration {
+        return lock(&self.state).get_elapsed(Instant::now());
+    }
+
+    pub fn get_rate(&self) -> f64 {
+        return lock(&self.state).get_rate(Instant::now());
+    }
+
+    pub fn get_eta(&self) -> Option<Duration> {
+        return lock(&self.state).get_eta(Instant::now());
+    }
+
+    pub(crate) fn render_line(&self, width: u16, now: Instant) -> Vec<StringPlus> {
+        let state = lock(&self.state);
+        let mut pieces: Vec<(StringPlus, bool)> = Vec::new();
+        for segment in &state.style.template {
+            let text = segment.to_string();
+            let mut rest = text.as_str();
+            while let Some(start) = rest.find('{') {
+                let Some(length) = rest[start..].find('}') else {
+                    break;
+                };
+                let key = &rest[start + 1..start + length];
+                pieces.push((segment.with_string(rest[..start].to_string()), false));
+                match (key, state.get_value(key, now)) {
+                    ("bar", _) => pieces.push((segment.with_string(String::new()), true)),
+                    (_, Some(value)) => pieces.push((segment.with_string(value), false)),
+                    (_, None) => pieces.push((
+                        segment.with_string(rest[start..=start + length].into()),
+                        false,
+                    )),
+                }
+                rest = &rest[start + length + 1..];
+            }
+            pieces.push((segment.with_string(rest.to_string()), false));
+        }
+        let used: usize = pieces
+            .iter()
+            .filter(|(_, is_bar)| !is_bar)
+            .map(|(piece, _)| piece.width())
+            .sum();
+        let bar_count = pieces.iter().filter(|(_, is_bar)| *is_bar).count().max(1);
+        let bar_width = (width as usize).saturating_sub(used) / bar_count;
+        let mut remaining = width as usize;
+        let mut line: Vec<StringPlus> = Vec::new();
+        for (piece, is_bar) in pieces {
+            let piece = match is_bar {
+                true => piece.with_string(state.get_bar(bar_width)),
+                false => piece,
+            };
+            let piece = piece.truncate(remaining);
+            remaining -= piece.width();
+            if !piece.to_string().is_empty() {
+                line.push(piece);
+            }
+        }
+        return merge_cells(line);
+    }
+}
+
+impl Widget for ProgressBar {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>> {
+        if height == 0 {
+            return Vec::new();
+        }
+        return vec![self.render_line(width, Instant::now())];
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProgressGroupState {
+    bars: Vec<ProgressBar>,
+    log_lines: VecDeque<StringPlus>,
+    drawn_lines: u16,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ProgressGroup {
+    state: Arc<Mutex<ProgressGroupState>>,
+}
+
+impl ProgressGroup {
+    pub fn new() -> ProgressGroup {
+        return ProgressGroup::default();
+    }
+
+    pub fn add(&self, bar: ProgressBar) -> ProgressBar {
+        lock(&self.state).bars.push(bar.clone());
+        return bar;
+    }
+
+    pub fn remove(&self, bar: &ProgressBar) {
+        lock(&self.state)
+            .bars
+            .retain(|other| !Arc::ptr_eq(&other.state, &bar.state));
+    }
+
+    pub fn get_bars(&self) -> Vec<ProgressBar> {
+        return lock(&self.state).bars.clone();
+    }
+
+    pub fn println<T: Into<StringPlus>>(&self, line: T) {
+        lock(&self.state).log_lines.push_back(line.into());
+    }
+
+    pub fn is_finished(&self) -> bool {
+        return lock(&self.state).bars.iter().all(|bar| bar.is_finished());
+    }
+
+    pub fn draw(&self, tui_terminal: &mut TuiTerminal) {
+        let (width, height) = match tui_terminal.get_teminal_size() {
+            Ok((width, height)) if width > 0 && height > 0 => (width, height),
+            _ => DEFAULT_TERMINAL_SIZE,
+        };
+        let mut state = lock(&self.state);
+        if state.drawn_lines > 0 {
+            tui_terminal.shift_cursor(CursorNav::Column(1));
+            if state.drawn_lines > 1 {
+                tui_terminal.shift_cursor(CursorNav::Up(state.drawn_lines - 1));
+            }
+            tui_terminal.clear_below_cursor();
+        }
+        while let Some(line) = state.log_lines.pop_front() {
+            tui_terminal.println(line);
+        }
+        let now = Instant::now();
+        let lines: Vec<Vec<StringPlus>> = state
+            .bars
+            .iter()
+            .take(height.saturating_sub(1).max(1) as usize)
+            .map(|bar| bar.render_line(width, now))
+            .collect();
+        for (index, line) in lines.iter().enumerate() {
+            if index != 0 {
+                tui_terminal.write("\n");
+            }
+            for span in line {
+                tui_terminal.write(span.clone());
+            }
+        }
+        state.drawn_lines = lines.len() as u16;
+    }
+
+    pub fn finish(&self, tui_terminal: &mut TuiTerminal) {
+        self.draw(tui_terminal);
+        let mut state = lock(&self.state);
+        if state.drawn_lines > 0 {
+            tui_terminal.println("");
+        }
+        state.drawn_lines = 0;
+        state.bars.clear();
+    }
+
+    pub fn draw_until_finished(&self, tui_terminal: &mut TuiTerminal, interval: Duration) {
+        while !self.is_finished() {
+            self.draw(tui_terminal);
+            thread::sleep(interval);
+        }
+        self.finish(tui_terminal);
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    progress::{format_duration, ProgressBar, ProgressGroup, ProgressStyle},
+    string_plus::{StringPlus, StringPlusTrait},
+    tui_enums::Color,
+};
+
+fn to_text(line: &[StringPlus]) -> String {
+    return line.iter().map(|span| span.to_string()).collect();
+}
+
+#[test]
+fn test_format_duration() -> Result<(), String> {
+    for (seconds, expected) in [(0, "00:00"), (75, "01:15"), (3725, "1:02:05")] {
+        if format_duration(Duration::from_secs(seconds)) != expected {
+            Err(format!(
+                "{} formatted as {:?}",
+                seconds,
+                format_duration(Duration::from_secs(seconds))
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_progress_template() -> Result<(), String> {
+    let bar = ProgressBar::new(100).message("copy");
+    bar.inc(50);
+    let later = Instant::now() + Duration::from_secs(10);
+    let text = to_text(&bar.render_line(44, later));
+    if text != "copy ██████░░░░░░ 50/100 (50%) 5/s ETA 00:10" || text.chars().count() != 44
+    {
+        Err(format!("{:?}", text))?;
+    }
+    let style = ProgressStyle::default_bar()
+        .template(vec![
+            "[".into(),
+            "{bar}".set_font_color(Color::Green),
+            "] {pos} {unknown}".into(),
+        ])
+        .progress_characters('#', '-');
+    let line = bar.style(style).render_line(23, later);
+    if to_text(&line) != "[####----] 50 {unknown}" {
+        Err(format!("{:?}", to_text(&line)))?;
+    }
+    if line[1].get_font_color() != Color::Green || line[0].get_font_color() != Color::Default {
+        Err(format!("Segment styling lost: {:?}", line))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_progress_truncates_to_width() -> Result<(), String> {
+    let bar = ProgressBar::new(10).message("a very long message that will not fit");
+    let text = to_text(&bar.render_line(20, Instant::now()));
+    if text.chars().count() != 20 {
+        Err(format!("{:?}", text))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_spinner() -> Result<(), String> {
+    let spinner = ProgressBar::spinner().message("waiting");
+    let text = to_text(&spinner.render_line(40, Instant::now()));
+    if text != "⠋ waiting [00:00]" {
+        Err(format!("{:?}", text))?;
+    }
+    spinner.finish_with_message("done");
+    let text = to_text(&spinner.render_line(40, Instant::now()));
+    if text != "✔ done [00:00]" || spinner.get_eta() != Some(Duration::ZERO) {
+        Err(format!("{:?}", text))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_progress_across_threads() -> Result<(), String> {
+    let group = ProgressGroup::new();
+    let bars: Vec<ProgressBar> = (0..4).map(|_| group.add(ProgressBar::new(100))).collect();
+    let workers: Vec<_> = bars
+        .iter()
+        .cloned()
+        .map(|bar| {
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    bar.inc(1);
+                }
+                bar.finish();
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().map_err(|_| "Worker panicked")?;
+    }
+    if !group.is_finished() || bars.iter().any(|bar| bar.get_position() != 100) {
+        Err("Progress lost across threads")?;
+    }
+    group.remove(&bars[0]);
+    if group.get_bars().len() != 3 {
+        Err("Bar not removed")?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,65 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl Rect {
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Rect {
+        return Rect {
+            x,
+            y,
+            width,
+            height,
+        };
+    }
+
+    pub fn left(&self) -> u16 {
+        return self.x;
+    }
+
+    pub fn top(&self) -> u16 {
+        return self.y;
+    }
+
+    pub fn right(&self) -> u16 {
+        return self.x.saturating_add(self.width);
+    }
+
+    pub fn bottom(&self) -> u16 {
+        return self.y.saturating_add(self.height);
+    }
+
+    pub fn area(&self) -> u32 {
+        return self.width as u32 * self.height as u32;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.width == 0 || self.height == 0;
+    }
+
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        return x >= self.left() && x < self.right() && y >= self.top() && y < self.bottom();
+    }
+
+    pub fn inner(&self, margin: u16) -> Rect {
+        let horizontal = margin.saturating_mul(2).min(self.width);
+        let vertical = margin.saturating_mul(2).min(self.height);
+        return Rect {
+            x: self.x.saturating_add(horizontal / 2),
+            y: self.y.saturating_add(vertical / 2),
+            width: self.width - horizontal,
+            height: self.height - vertical,
+        };
+    }
+
+    pub fn intersection(&self, other: Rect) -> Rect {
+        let x = self.left().max(other.left());
+        let y = self.top().max(other.top());
+        let right = self.right().min(other.right()).max(x);
+        let bottom = self.bottom().min(other.bottom()).max(y);
+        return Rect::new(x, y, right - x, bottom - y);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    box_drawing::BorderStyle, rect::Rect, string_plus::StringPlus, tui_terminal::TuiTerminal,
+    widget::Widget,
+};
+
+#[derive(Debug)]
+pub struct Region<'a> {
+    tui_terminal: &'a mut TuiTerminal,
+    rect: Rect,
+    cursor_x: u16,
+    cursor_y: u16,
+}
+
+pub(crate) fn clip_span(string_plus: &StringPlus, width: usize) -> StringPlus {
+    return string_plus.truncate_with_ellipsis(width, "");
+}
+
+pub(crate) fn get_absolute_rect(area: Rect, rect: Rect) -> Rect {
+    let translated = Rect::new(
+        area.x.saturating_add(rect.x.saturating_sub(1)),
+        area.y.saturating_add(rect.y.saturating_sub(1)),
+        rect.width,
+        rect.height,
+    );
+    return translated.intersection(area);
+}
+
+pub(crate) fn get_absolute_position(area: Rect, x: u16, y: u16) -> Option<(u16, u16)> {
+    if x == 0 || y == 0 || x > area.width || y > area.height {
+        return None;
+    }
+    let absolute_x = area.x as u32 + x as u32 - 1;
+    let absolute_y = area.y as u32 + y as u32 - 1;
+    if absolute_x > u16::MAX as u32 || absolute_y > u16::MAX as u32 {
+        return None;
+    }
+    return Some((absolute_x as u16, absolute_y as u16));
+}
+
+pub(crate) fn place_span(
+    area: Rect,
+    x: u16,
+    y: u16,
+    string_plus: &StringPlus,
+) -> Option<((u16, u16), StringPlus)> {
+    let position = get_absolute_position(area, x, y)?;
+    let clipped = clip_span(string_plus, (area.width - x + 1) as usize);
+    if clipped.width() == 0 {
+        return None;
+    }
+    return Some((position, clipped));
+}
+
+impl<'a> Region<'a> {
+    pub fn new(tui_terminal: &'a mut TuiTerminal, rect: Rect) -> Region<'a> {
+        return Region {
+            tui_terminal,
+            rect,
+            cursor_x: 1,
+            cursor_y: 1,
+        };
+    }
+
+    pub fn get_rect(&self) -> Rect {
+        return self.rect;
+    }
+
+    pub fn get_area(&self) -> Rect {
+        return Rect::new(1, 1, self.rect.width, self.rect.height);
+    }
+
+    pub fn to_absolute(&self, rect: Rect) -> Rect {
+        return get_absolute_rect(self.rect, rect);
+    }
+
+    pub fn sub_region(&mut self, rect: Rect) -> Region<'_> {
+        let rect = self.to_absolute(rect);
+        return Region::new(self.tui_terminal, rect);
+    }
+
+    pub fn get_cursor_position(&self) -> (u16, u16) {
+        return (self.cursor_x, self.cursor_y);
+    }
+
+    pub fn set_cursor_position(&mut self, x: u16, y: u16) {
+        self.cursor_x = x.max(1);
+        self.cursor_y = y.max(1);
+    }
+
+    fn write_span(&mut self, string_plus: &StringPlus) {
+        if let Some(((x, y), clipped)) =
+            place_span(self.rect, self.cursor_x, self.cursor_y, string_plus)
+        {
+            self.tui_terminal.set_cursor_position(x, y);
+            self.tui_terminal.write(clipped);
+        }
+        let width = string_plus.width().min(u16::MAX as usize) as u16;
+        self.cursor_x = self.cursor_x.saturating_add(width);
+    }
+
+    pub fn print<T: Into<StringPlus>>(&mut self, string_plus: T) {
+        let string_plus: StringPlus = string_plus.into();
+        let string: String = (&string_plus).into();
+        for (line_number, line) in string.split('\n').enumerate() {
+            if line_number != 0 {
+                self.cursor_x = 1;
+                self.cursor_y = self.cursor_y.saturating_add(1);
+            }
+            self.write_span(&string_plus.with_string(line.to_string()));
+        }
+    }
+
+    pub fn println<T: Into<StringPlus>>(&mut self, string_plus: T) {
+        self.print(string_plus);
+        self.cursor_x = 1;
+        self.cursor_y = self.cursor_y.saturating_add(1);
+    }
+
+    pub fn print_at<T: Into<StringPlus>>(&mut self, x: u16, y: u16, string_plus: T) {
+        self.set_cursor_position(x, y);
+        self.print(string_plus);
+    }
+
+    pub fn print_lines(&mut self, x: u16, y: u16, lines: &[Vec<StringPlus>]) {
+        for (row, line) in lines.iter().enumerate() {
+            self.set_cursor_position(x, y.saturating_add(row as u16));
+            for string_plus in line {
+                self.write_span(string_plus);
+            }
+        }
+    }
+
+    pub fn draw_widget<W: Widget>(&mut self, widget: &W) {
+        self.print_lines(1, 1, &widget.render(self.rect.width, self.rect.height));
+    }
+
+    pub fn clear(&mut self) {
+        let blank = " ".repeat(self.rect.width as usize);
+        for row in 1..=self.rect.height {
+            self.print_at(1, row, blank.as_str());
+        }
+        self.set_cursor_position(1, 1);
+    }
+
+    fn draw_line_cells(&mut self, cells: Vec<((u16, u16), u8)>, border_style: BorderStyle) {
+        let rect = self.rect;
+        let cells = cells
+            .into_iter()
+            .filter_map(|((x, y), mask)| Some((get_absolute_position(rect, x, y)?, mask)))
+            .collect();
+        self.tui_terminal.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_horizontal_line(&mut self, x: u16, y: u16, length: u16, border_style: BorderStyle) {
+        let cells = TuiTerminal::get_horizontal_line_cells(x.max(1), y.max(1), length);
+        self.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_vertical_line(&mut self, x: u16, y: u16, length: u16, border_style: BorderStyle) {
+        let cells = TuiTerminal::get_vertical_line_cells(x.max(1), y.max(1), length);
+        self.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_box(&mut self, rect: Rect, border_style: BorderStyle) {
+        if rect.width < 2 || rect.height < 2 {
+            return;
+        }
+        let (x, y) = (rect.x.max(1), rect.y.max(1));
+        let right = x.saturating_add(rect.width - 1);
+        let bottom = y.saturating_add(rect.height - 1);
+        let mut cells = TuiTerminal::get_horizontal_line_cells(x, y, rect.width);
+        cells.append(&mut TuiTerminal::get_horizontal_line_cells(
+            x, bottom, rect.width,
+        ));
+        cells.append(&mut TuiTerminal::get_vertical_line_cells(x, y, rect.height));
+        cells.append(&mut TuiTerminal::get_vertical_line_cells(
+            right,
+            y,
+            rect.height,
+        ));
+        self.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_titled_box<T: Into<StringPlus>>(
+        &mut self,
+        rect: Rect,
+        border_style: BorderStyle,
+        title: T,
+    ) {
+        self.draw_box(rect, border_style);
+        if rect.width < 5 {
+            return;
+        }
+        let title: StringPlus = title.into();
+        let title = title.truncate(rect.width as usize - 4);
+        self.print_at(rect.x.max(1).saturating_add(1), rect.y.max(1), " ");
+        self.print(title);
+        self.print(" ");
+    }
+
+    pub fn get_terminal(&mut self) -> &mut TuiTerminal {
+        return self.tui_terminal;
+    }
+}
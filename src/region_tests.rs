@@ -0,0 +1,116 @@
+use crate::{
+    rect::Rect,
+    region::{clip_span, get_absolute_position, get_absolute_rect, place_span},
+    string_plus::StringPlus,
+};
+
+#[test]
+fn test_clip_span() -> Result<(), String> {
+    let cases = [
+        ("Hello World", 5, "Hello"),
+        ("Hello", 10, "Hello"),
+        ("日本語", 5, "日本"),
+        ("e\u{301}e\u{301}e\u{301}", 2, "e\u{301}e\u{301}"),
+        ("abc", 0, ""),
+    ];
+    for (input, width, expected) in cases {
+        let clipped = clip_span(&StringPlus::from(input), width).to_string();
+        if clipped != expected {
+            Err(format!(
+                "{:?} at {}: {:?} != {:?}",
+                input, width, clipped, expected
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_absolute_rect_nesting() -> Result<(), String> {
+    let area = Rect::new(10, 5, 20, 10);
+    let cases = [
+        (Rect::new(1, 1, 5, 5), Rect::new(10, 5, 5, 5)),
+        (Rect::new(3, 4, 5, 2), Rect::new(12, 8, 5, 2)),
+        (Rect::new(18, 1, 5, 3), Rect::new(27, 5, 3, 3)),
+        (Rect::new(1, 9, 4, 5), Rect::new(10, 13, 4, 2)),
+        (Rect::new(25, 1, 5, 5), Rect::new(34, 5, 0, 5)),
+    ];
+    for (rect, expected) in cases {
+        let absolute = get_absolute_rect(area, rect);
+        if absolute != expected {
+            Err(format!("{:?}: {:?} != {:?}", rect, absolute, expected))?;
+        }
+    }
+    let nested = get_absolute_rect(area, Rect::new(5, 5, 10, 10));
+    let inner = get_absolute_rect(nested, Rect::new(2, 2, 20, 20));
+    if nested != Rect::new(14, 9, 10, 6) || inner != Rect::new(15, 10, 9, 5) {
+        Err(format!("{:?} {:?}", nested, inner))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_absolute_position_edges() -> Result<(), String> {
+    let area = Rect::new(10, 5, 4, 3);
+    let cases = [
+        ((1, 1), Some((10, 5))),
+        ((4, 3), Some((13, 7))),
+        ((0, 1), None),
+        ((1, 0), None),
+        ((5, 1), None),
+        ((1, 4), None),
+    ];
+    for ((x, y), expected) in cases {
+        let position = get_absolute_position(area, x, y);
+        if position != expected {
+            Err(format!("({}, {}): {:?} != {:?}", x, y, position, expected))?;
+        }
+    }
+    let corner = Rect::new(u16::MAX, u16::MAX, 4, 4);
+    if get_absolute_position(corner, 2, 1).is_some() {
+        Err("Position past u16::MAX should be clipped".to_string())?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_place_span_clipping() -> Result<(), String> {
+    let area = Rect::new(3, 2, 5, 2);
+    let cases = [
+        ((1, 1), "Hi", Some(((3, 2), "Hi"))),
+        ((4, 1), "Hello", Some(((6, 2), "He"))),
+        ((5, 2), "日本", None),
+        ((6, 1), "Hi", None),
+        ((1, 3), "Hi", None),
+        ((2, 2), "", None),
+    ];
+    for ((x, y), input, expected) in cases {
+        let placed = place_span(area, x, y, &StringPlus::from(input))
+            .map(|(position, span)| (position, span.to_string()));
+        let expected = expected.map(|(position, span)| (position, span.to_string()));
+        if placed != expected {
+            Err(format!(
+                "{:?} at ({}, {}): {:?} != {:?}",
+                input, x, y, placed, expected
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_place_widget_lines() -> Result<(), String> {
+    let area = Rect::new(5, 5, 4, 2);
+    let lines = ["abcdef", "gh", "ijkl"];
+    let placed: Vec<((u16, u16), String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(row, line)| place_span(area, 1, row as u16 + 1, &StringPlus::from(*line)))
+        .map(|(position, span)| (position, span.to_string()))
+        .collect();
+    let expected = vec![((5, 5), "abcd".to_string()), ((5, 6), "gh".to_string())];
+    if placed != expected {
+        Err(format!("{:?} != {:?}", placed, expected))?;
+    }
+    return Ok(());
+}
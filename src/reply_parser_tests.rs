@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    tui_enums::Color,
+    tui_events::TuiEvents,
+    tui_io::{
+        input_parser::{parse_sequence, BufferedInput},
+        reply_parser::{parse_color_reply, parse_cursor_position_reply, read_reply_sequence},
+    },
+};
+
+#[test]
+fn test_parse_color_reply() -> Result<(), String> {
+    let cases = [
+        ("rgb:1e1e/1e1e/2e2e", Some(Color::RGB(0x1e, 0x1e, 0x2e))),
+        ("rgb:ffff/0000/8080", Some(Color::RGB(255, 0, 128))),
+        ("rgb:f/0/8", Some(Color::RGB(255, 0, 136))),
+        ("rgba:ff/00/00/ff", Some(Color::RGB(255, 0, 0))),
+        ("rgb:ffff/0000", None),
+        ("#ffffff", None),
+    ];
+    for (reply, expected) in cases {
+        if parse_color_reply(reply) != expected {
+            Err(format!("{:?}: {:?}", reply, parse_color_reply(reply)))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_parse_cursor_position_reply() -> Result<(), String> {
+    let cases = [
+        ("[5;10R", Some((10, 5))),
+        ("[1;1R", Some((1, 1))),
+        ("[99999;1R", None),
+        ("[5R", None),
+        ("[5;10H", None),
+    ];
+    for (reply, expected) in cases {
+        if parse_cursor_position_reply(reply) != expected {
+            Err(format!(
+                "{:?}: {:?}",
+                reply,
+                parse_cursor_position_reply(reply)
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_parse_queued_user_input() -> Result<(), String> {
+    let events = parse_sequence("a\x1b[A\x1b[<0;4;7M", true);
+    let expected = vec![
+        TuiEvents::AsciiReadable('a'),
+        TuiEvents::UpArrow,
+        TuiEvents::LeftClick((4, 7)),
+    ];
+    if events != expected {
+        Err(format!("{:?} != {:?}", events, expected))?;
+    }
+    return Ok(());
+}
+
+fn is_cell_size_reply(reply: &str) -> bool {
+    return reply.starts_with("[6;") && reply.ends_with('t');
+}
+
+#[test]
+fn test_read_reply_between_user_input() -> Result<(), String> {
+    let input = BufferedInput::from_input("a\x1bOA\x1b[6;20;10t\x1b[Bé", true);
+    let deadline = Instant::now() + Duration::from_millis(500);
+    let mut user_input = String::new();
+    let reply = read_reply_sequence(&input, is_cell_size_reply, deadline, &mut user_input);
+    if reply.as_deref().ok() != Some("[6;20;10t") {
+        Err(format!("{:?}", reply))?;
+    }
+    let events = parse_sequence(&user_input, true);
+    let expected = vec![TuiEvents::AsciiReadable('a'), TuiEvents::UpArrow];
+    if events != expected {
+        Err(format!("{:?} != {:?}", events, expected))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_read_reply_cut_off_by_timeout() -> Result<(), String> {
+    let cases = [
+        ("\x1b[6;20", vec![]),
+        ("\x1b]11;rgb:1e1e/", vec![]),
+        ("\x1b[<0;4;7M\x1b[6;", vec![TuiEvents::LeftClick((4, 7))]),
+    ];
+    for (sequence, expected) in cases {
+        let input = BufferedInput::from_input(sequence, true);
+        let deadline = Instant::now() + Duration::from_millis(500);
+        let mut user_input = String::new();
+        let reply = read_reply_sequence(&input, is_cell_size_reply, deadline, &mut user_input);
+        if reply.is_ok() {
+            Err(format!("{:?}: {:?}", sequence, reply))?;
+        }
+        let events = parse_sequence(&user_input, true);
+        if events != expected {
+            Err(format!("{:?}: {:?} != {:?}", sequence, events, expected))?;
+        }
+    }
+    return Ok(());
+}
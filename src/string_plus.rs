@@ -1,4 +1,11 @@
-use crate::{font_settings::FontSettings, Color, ThreeBool};
+use crate::{
+    font_settings::FontSettings,
+    text_wrap::{wrap, WrapOptions},
+    theme::ThemeRole,
+    tui_enums::{Alignment, DecLineMode, UnderlineStyle},
+    unicode_width::{grapheme_width, graphemes, str_width},
+    Color, ThreeBool,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub enum DecCharSet {
@@ -17,11 +24,83 @@ pub enum DecCharSet {
     LessEqual,
     GreaterEqual,
     NotEqual,
+    Blank,
+    Diamond,
+    Checkerboard,
+    HorizontalTab,
+    FormFeed,
+    CarriageReturn,
+    LineFeed,
+    Degree,
+    PlusMinus,
+    NewLine,
+    VerticalTab,
+    ScanLine1,
+    ScanLine3,
+    ScanLine7,
+    ScanLine9,
+    Pi,
+    Pound,
+    Bullet,
     Other(char),
 }
 
+const DEC_SPECIAL_GRAPHICS: [(char, char); 32] = [
+    ('\x5f', '\u{00A0}'),
+    ('\x60', '◆'),
+    ('\x61', '▒'),
+    ('\x62', '␉'),
+    ('\x63', '␌'),
+    ('\x64', '␍'),
+    ('\x65', '␊'),
+    ('\x66', '°'),
+    ('\x67', '±'),
+    ('\x68', '␤'),
+    ('\x69', '␋'),
+    ('\x6a', '┘'),
+    ('\x6b', '┐'),
+    ('\x6c', '┌'),
+    ('\x6d', '└'),
+    ('\x6e', '┼'),
+    ('\x6f', '⎺'),
+    ('\x70', '⎻'),
+    ('\x71', '─'),
+    ('\x72', '⎼'),
+    ('\x73', '⎽'),
+    ('\x74', '├'),
+    ('\x75', '┤'),
+    ('\x76', '┴'),
+    ('\x77', '┬'),
+    ('\x78', '│'),
+    ('\x79', '≤'),
+    ('\x7a', '≥'),
+    ('\x7b', 'π'),
+    ('\x7c', '≠'),
+    ('\x7d', '£'),
+    ('\x7e', '·'),
+];
+
+pub(crate) fn dec_to_unicode(code: char) -> char {
+    return DEC_SPECIAL_GRAPHICS
+        .iter()
+        .find(|(dec_code, _)| *dec_code == code)
+        .map(|(_, unicode)| *unicode)
+        .unwrap_or(code);
+}
+
+pub(crate) fn unicode_to_dec(unicode: char) -> char {
+    if unicode == '█' {
+        return '\x61';
+    }
+    return DEC_SPECIAL_GRAPHICS
+        .iter()
+        .find(|(_, unicode_char)| *unicode_char == unicode)
+        .map(|(dec_code, _)| *dec_code)
+        .unwrap_or(unicode);
+}
+
 impl DecCharSet {
-    fn get_code(&self) -> String {
+    pub fn get_code(&self) -> char {
         return match self {
             &DecCharSet::TopLeft => '\x6c',
             &DecCharSet::TopMiddle => '\x77',
@@ -38,9 +117,62 @@ impl DecCharSet {
             &DecCharSet::LessEqual => '\x79',
             &DecCharSet::GreaterEqual => '\x7a',
             &DecCharSet::NotEqual => '\x7c',
+            &DecCharSet::Blank => '\x5f',
+            &DecCharSet::Diamond => '\x60',
+            &DecCharSet::Checkerboard => '\x61',
+            &DecCharSet::HorizontalTab => '\x62',
+            &DecCharSet::FormFeed => '\x63',
+            &DecCharSet::CarriageReturn => '\x64',
+            &DecCharSet::LineFeed => '\x65',
+            &DecCharSet::Degree => '\x66',
+            &DecCharSet::PlusMinus => '\x67',
+            &DecCharSet::NewLine => '\x68',
+            &DecCharSet::VerticalTab => '\x69',
+            &DecCharSet::ScanLine1 => '\x6f',
+            &DecCharSet::ScanLine3 => '\x70',
+            &DecCharSet::ScanLine7 => '\x72',
+            &DecCharSet::ScanLine9 => '\x73',
+            &DecCharSet::Pi => '\x7b',
+            &DecCharSet::Pound => '\x7d',
+            &DecCharSet::Bullet => '\x7e',
             &DecCharSet::Other(code) => code,
+        };
+    }
+
+    pub fn get_unicode(&self) -> char {
+        return match self {
+            &DecCharSet::Block => '█',
+            _ => dec_to_unicode(self.get_code()),
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hyperlink {
+    pub url: String,
+    pub id: Option<String>,
+}
+
+pub(crate) const HYPERLINK_CLOSE_CODE: &str = "\x1b]8;;\x1b\\";
+
+fn percent_encode(text: &str, reserved: &[u8]) -> String {
+    let mut encoded = String::new();
+    for byte in text.bytes() {
+        match (0x21..=0x7E).contains(&byte) && !reserved.contains(&byte) {
+            true => encoded.push(byte as char),
+            false => encoded += &format!("%{:02X}", byte),
         }
-        .into();
+    }
+    return encoded;
+}
+
+impl Hyperlink {
+    pub(crate) fn get_open_code(&self) -> String {
+        let params = match &self.id {
+            Some(id) => "id=".to_string() + &percent_encode(id, b"%:;="),
+            None => String::new(),
+        };
+        return "\x1b]8;".to_string() + &params + ";" + &percent_encode(&self.url, b"") + "\x1b\\";
     }
 }
 
@@ -48,6 +180,8 @@ impl DecCharSet {
 pub struct StringPlus {
     string: String,
     font_settings: FontSettings,
+    hyperlink: Option<Hyperlink>,
+    role: Option<ThemeRole>,
 }
 
 impl StringPlus {
@@ -80,17 +214,123 @@ impl StringPlus {
         return self.font_settings.is_blinking;
     }
 
+    pub fn get_strikethrough(&self) -> ThreeBool {
+        return self.font_settings.is_strikethrough;
+    }
+
+    pub fn get_dim(&self) -> ThreeBool {
+        return self.font_settings.is_dim;
+    }
+
+    pub fn get_overlined(&self) -> ThreeBool {
+        return self.font_settings.is_overlined;
+    }
+
+    pub fn get_hidden(&self) -> ThreeBool {
+        return self.font_settings.is_hidden;
+    }
+
+    pub fn get_underline_style(&self) -> UnderlineStyle {
+        return self.font_settings.underline_style;
+    }
+
+    pub fn get_underline_color(&self) -> Color {
+        return self.font_settings.underline_color;
+    }
+
     pub fn get_font_settings(&self) -> &FontSettings {
         return &self.font_settings;
     }
 
+    pub fn get_hyperlink(&self) -> Option<&Hyperlink> {
+        return self.hyperlink.as_ref();
+    }
+
+    pub fn get_role(&self) -> Option<ThemeRole> {
+        return self.role;
+    }
+
     pub fn get_dec_line(&self) -> bool {
         return self.font_settings.is_dec_line;
     }
 
+    pub fn get_dec_line_mode(&self) -> DecLineMode {
+        return self.font_settings.dec_line_mode;
+    }
+
     pub fn len(&self) -> usize {
         return self.string.len();
     }
+
+    pub fn width(&self) -> usize {
+        return str_width(&self.string);
+    }
+
+    pub fn graphemes(&self) -> Vec<&str> {
+        return graphemes(&self.string);
+    }
+
+    pub(crate) fn with_string(&self, string: String) -> StringPlus {
+        return StringPlus {
+            string,
+            font_settings: self.font_settings,
+            hyperlink: self.hyperlink.clone(),
+            role: self.role,
+        };
+    }
+
+    pub fn truncate(&self, width: usize) -> StringPlus {
+        return self.truncate_with_ellipsis(width, "\u{2026}");
+    }
+
+    pub fn truncate_with_ellipsis(&self, width: usize, ellipsis: &str) -> StringPlus {
+        if self.width() <= width {
+            return self.clone();
+        }
+        let ellipsis_width = str_width(ellipsis);
+        if ellipsis_width > width {
+            return self.with_string(String::new());
+        }
+        let mut string = String::new();
+        let mut used_width = 0;
+        for grapheme in self.graphemes() {
+            let grapheme_width = grapheme_width(grapheme);
+            if used_width + grapheme_width + ellipsis_width > width {
+                break;
+            }
+            string += grapheme;
+            used_width += grapheme_width;
+        }
+        string += ellipsis;
+        return self.with_string(string);
+    }
+
+    pub fn align(&self, width: usize, alignment: Alignment) -> StringPlus {
+        let string_plus = self.truncate(width);
+        let padding = width - string_plus.width();
+        let (left, right) = match alignment {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        };
+        return self.with_string(" ".repeat(left) + &string_plus.string + &" ".repeat(right));
+    }
+
+    pub fn pad_left(&self, width: usize) -> StringPlus {
+        return self.align(width, Alignment::Right);
+    }
+
+    pub fn pad_right(&self, width: usize) -> StringPlus {
+        return self.align(width, Alignment::Left);
+    }
+
+    pub fn center(&self, width: usize) -> StringPlus {
+        return self.align(width, Alignment::Center);
+    }
+
+    pub fn wrap(&self, options: WrapOptions) -> Vec<Vec<StringPlus>> {
+        return wrap(std::slice::from_ref(self), options);
+    }
 }
 
 pub trait StringPlusTrait {
@@ -101,7 +341,17 @@ pub trait StringPlusTrait {
     fn set_italics(self, is_blinking: ThreeBool) -> StringPlus;
     fn set_inverted(self, is_inverted: ThreeBool) -> StringPlus;
     fn set_blinking(self, is_blinking: ThreeBool) -> StringPlus;
+    fn set_strikethrough(self, is_strikethrough: ThreeBool) -> StringPlus;
+    fn set_dim(self, is_dim: ThreeBool) -> StringPlus;
+    fn set_overlined(self, is_overlined: ThreeBool) -> StringPlus;
+    fn set_hidden(self, is_hidden: ThreeBool) -> StringPlus;
+    fn set_underline_style(self, underline_style: UnderlineStyle) -> StringPlus;
+    fn set_underline_color(self, color: Color) -> StringPlus;
     fn set_font_settings(self, font_settings: FontSettings) -> StringPlus;
+    fn set_hyperlink(self, url: &str) -> StringPlus;
+    fn set_hyperlink_with_id(self, url: &str, id: &str) -> StringPlus;
+    fn set_role(self, role: ThemeRole) -> StringPlus;
+    fn set_dec_line_mode(self, dec_line_mode: DecLineMode) -> StringPlus;
 }
 
 impl<T: Into<StringPlus>> StringPlusTrait for T {
@@ -146,11 +396,77 @@ impl<T: Into<StringPlus>> StringPlusTrait for T {
         return string_plus;
     }
 
+    fn set_strikethrough(self, is_strikethrough: ThreeBool) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.is_strikethrough = is_strikethrough;
+        return string_plus;
+    }
+
+    fn set_dim(self, is_dim: ThreeBool) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.is_dim = is_dim;
+        return string_plus;
+    }
+
+    fn set_overlined(self, is_overlined: ThreeBool) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.is_overlined = is_overlined;
+        return string_plus;
+    }
+
+    fn set_hidden(self, is_hidden: ThreeBool) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.is_hidden = is_hidden;
+        return string_plus;
+    }
+
+    fn set_underline_style(self, underline_style: UnderlineStyle) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.underline_style = underline_style;
+        return string_plus;
+    }
+
+    fn set_underline_color(self, color: Color) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.underline_color = color;
+        return string_plus;
+    }
+
     fn set_font_settings(self, font_settings: FontSettings) -> StringPlus {
         let mut string_plus: StringPlus = self.into();
         string_plus.font_settings = font_settings;
         return string_plus;
     }
+
+    fn set_hyperlink(self, url: &str) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.hyperlink = Some(Hyperlink {
+            url: url.to_string(),
+            id: None,
+        });
+        return string_plus;
+    }
+
+    fn set_hyperlink_with_id(self, url: &str, id: &str) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.hyperlink = Some(Hyperlink {
+            url: url.to_string(),
+            id: Some(id.to_string()),
+        });
+        return string_plus;
+    }
+
+    fn set_role(self, role: ThemeRole) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.role = Some(role);
+        return string_plus;
+    }
+
+    fn set_dec_line_mode(self, dec_line_mode: DecLineMode) -> StringPlus {
+        let mut string_plus: StringPlus = self.into();
+        string_plus.font_settings.dec_line_mode = dec_line_mode;
+        return string_plus;
+    }
 }
 
 impl From<&StringPlus> for String {
@@ -176,6 +492,8 @@ impl From<&str> for StringPlus {
         return StringPlus {
             string: value.to_string(),
             font_settings: FontSettings::default(),
+            hyperlink: None,
+            role: None,
         };
     }
 }
@@ -185,6 +503,8 @@ impl From<String> for StringPlus {
         return StringPlus {
             string: value,
             font_settings: FontSettings::default(),
+            hyperlink: None,
+            role: None,
         };
     }
 }
@@ -194,8 +514,10 @@ impl From<DecCharSet> for StringPlus {
         let mut font_settings: FontSettings = FontSettings::default();
         font_settings.is_dec_line = true;
         return StringPlus {
-            string: dec_line.get_code(),
+            string: dec_line.get_code().to_string(),
             font_settings: font_settings,
+            hyperlink: None,
+            role: None,
         };
     }
 }
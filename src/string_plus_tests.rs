@@ -0,0 +1,121 @@
+use crate::{
+    string_plus::{
+        unicode_to_dec, DecCharSet, Hyperlink, StringPlus, StringPlusTrait, HYPERLINK_CLOSE_CODE,
+    },
+    tui_enums::{Alignment, Color},
+};
+
+#[test]
+fn test_width_ascii_and_accents() -> Result<(), String> {
+    let string_plus: StringPlus = "héllo".into();
+    if string_plus.len() != 6 || string_plus.width() != 5 {
+        Err(format!("{} {}", string_plus.len(), string_plus.width()))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_width_wide_and_combining() -> Result<(), String> {
+    let cases: [(&str, usize); 6] = [
+        ("日本語", 6),
+        ("e\u{301}", 1),
+        ("\u{1F600}", 2),
+        ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", 2),
+        ("\u{2764}\u{FE0F}", 2),
+        ("\u{1F1FA}\u{1F1F8}", 2),
+    ];
+    for (string, expected) in cases {
+        let width = StringPlus::from(string).width();
+        if width != expected {
+            Err(format!("{:?}: {} != {}", string, width, expected))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_truncate() -> Result<(), String> {
+    let string_plus = "日本語テキスト".set_font_color(Color::Red);
+    let truncated = string_plus.truncate(6);
+    if truncated.to_string() != "日本\u{2026}" || truncated.get_font_color() != Color::Red {
+        Err(format!("{:?}", truncated))?;
+    }
+    let untouched = StringPlus::from("short").truncate(5);
+    if untouched.to_string() != "short" {
+        Err(format!("{:?}", untouched))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_align() -> Result<(), String> {
+    let string_plus: StringPlus = "ab".into();
+    let cases = [
+        (Alignment::Left, "ab   "),
+        (Alignment::Center, " ab  "),
+        (Alignment::Right, "   ab"),
+    ];
+    for (alignment, expected) in cases {
+        let aligned = string_plus.align(5, alignment).to_string();
+        if aligned != expected {
+            Err(format!("{:?}: {:?} != {:?}", alignment, aligned, expected))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_dec_char_set_unicode() -> Result<(), String> {
+    let cases = [
+        (DecCharSet::TopLeft, 'l', '┌'),
+        (DecCharSet::Checkerboard, 'a', '▒'),
+        (DecCharSet::Block, 'a', '█'),
+        (DecCharSet::Degree, 'f', '°'),
+        (DecCharSet::Pi, '{', 'π'),
+        (DecCharSet::Bullet, '~', '·'),
+        (DecCharSet::Other('q'), 'q', '─'),
+    ];
+    for (dec_char, code, unicode) in cases {
+        let string_plus: StringPlus = dec_char.into();
+        if dec_char.get_code() != code
+            || dec_char.get_unicode() != unicode
+            || string_plus.to_string() != code.to_string()
+            || !string_plus.get_dec_line()
+        {
+            Err(format!("{:?}: {:?}", dec_char, string_plus))?;
+        }
+        if unicode_to_dec(unicode) != code {
+            Err(format!("{:?}: {:?}", unicode, unicode_to_dec(unicode)))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_hyperlink_codes() -> Result<(), String> {
+    let cases = [
+        (
+            Hyperlink {
+                url: "https://example.com/a?b=c".into(),
+                id: None,
+            },
+            "\x1b]8;;https://example.com/a?b=c\x1b\\",
+        ),
+        (
+            Hyperlink {
+                url: "https://example.com/café menu".into(),
+                id: Some("link;1:é".into()),
+            },
+            "\x1b]8;id=link%3B1%3A%C3%A9;https://example.com/caf%C3%A9%20menu\x1b\\",
+        ),
+    ];
+    for (hyperlink, expected) in cases {
+        if hyperlink.get_open_code() != expected {
+            Err(format!("{:?} != {:?}", hyperlink.get_open_code(), expected))?;
+        }
+    }
+    if HYPERLINK_CLOSE_CODE != "\x1b]8;;\x1b\\" {
+        Err(format!("{:?}", HYPERLINK_CLOSE_CODE))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,594 @@
+use crate::{
+    rect::Rect,
+    string_plus::{StringPlus, StringPlusTrait},
+    theme::ThemeRole,
+    tui_events::TuiEvents,
+    tui_terminal::TuiTerminal,
+    unicode_width::{grapheme_width, graphemes, str_width},
+    widget::Widget,
+};
+
+const MAX_UNDO: usize = 200;
+const SCROLL_LINES: usize = 3;
+const TAB: &str = "    ";
+
+type Position = (usize, usize);
+type VisualRow = (usize, usize, usize);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: Position,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EditKind {
+    Insert,
+    Delete,
+    Paste,
+    Other,
+}
+
+#[derive(Clone, Debug)]
+pub struct TextArea {
+    lines: Vec<String>,
+    cursor: Position,
+    anchor: Option<Position>,
+    preferred_column: Option<usize>,
+    scroll: usize,
+    rect: Option<Rect>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    last_edit: Option<EditKind>,
+    is_pasting: bool,
+}
+
+pub fn wrap_line(line: &str, width: usize) -> Vec<(usize, usize)> {
+    let mut segments: Vec<(usize, usize)> = Vec::new();
+    let (mut start, mut position, mut row_width) = (0, 0, 0);
+    let mut last_space: Option<usize> = None;
+    for grapheme in graphemes(line) {
+        let grapheme_columns = grapheme_width(grapheme);
+        if width > 0 && row_width + grapheme_columns > width && position > start {
+            let end = match last_space {
+                Some(space) if space > start && space < position => space,
+                _ => position,
+            };
+            segments.push((start, end));
+            row_width = str_width(&line[end..position]);
+            start = end;
+            last_space = None;
+        }
+        row_width += grapheme_columns;
+        position += grapheme.len();
+        if grapheme == " " {
+            last_space = Some(position);
+        }
+    }
+    segments.push((start, line.len()));
+    return segments;
+}
+
+impl Default for TextArea {
+    fn default() -> TextArea {
+        return TextArea::new("");
+    }
+}
+
+impl TextArea {
+    pub fn new(text: &str) -> TextArea {
+        let mut text_area = TextArea {
+            lines: vec![String::new()],
+            cursor: (0, 0),
+            anchor: None,
+            preferred_column: None,
+            scroll: 0,
+            rect: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            last_edit: None,
+            is_pasting: false,
+        };
+        text_area.set_text(text);
+        return text_area;
+    }
+
+    pub fn get_text(&self) -> String {
+        return self.lines.join("\n");
+    }
+
+    pub fn set_text(&mut self, text: &str) {
+        self.lines = text
+            .replace("\r\n", "\n")
+            .split('\n')
+            .map(|line| line.replace('\t', TAB))
+            .collect();
+        self.cursor = (0, 0);
+        self.anchor = None;
+        self.scroll = 0;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.last_edit = None;
+    }
+
+    pub fn get_lines(&self) -> &[String] {
+        return &self.lines;
+    }
+
+    pub fn get_cursor(&self) -> Position {
+        return self.cursor;
+    }
+
+    pub fn set_cursor(&mut self, row: usize, column: usize) {
+        let row = row.min(self.lines.len() - 1);
+        let mut column = column.min(self.lines[row].len());
+        while !self.lines[row].is_char_boundary(column) {
+            column -= 1;
+        }
+        self.move_to((row, column), false);
+    }
+
+    pub fn get_scroll(&self) -> usize {
+        return self.scroll;
+    }
+
+    pub fn set_rect(&mut self, rect: Rect) {
+        self.rect = Some(rect);
+        self.ensure_cursor_visible();
+    }
+
+    pub fn get_selection(&self) -> Option<(Position, Position)> {
+        let anchor = self.anchor?;
+        return match anchor.cmp(&self.cursor) {
+            std::cmp::Ordering::Less => Some((anchor, self.cursor)),
+            std::cmp::Ordering::Greater => Some((self.cursor, anchor)),
+            std::cmp::Ordering::Equal => None,
+        };
+    }
+
+    pub fn set_selection(&mut self, anchor: Position, cursor: Position) {
+        self.set_cursor(anchor.0, anchor.1);
+        self.anchor = Some(self.cursor);
+        self.set_cursor(cursor.0, cursor.1);
+        self.anchor = Some(self.anchor.unwrap_or(self.cursor));
+    }
+
+    pub fn select_all(&mut self) {
+        let last = self.lines.len() - 1;
+        self.set_selection((0, 0), (last, self.lines[last].len()));
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    pub fn get_selected_text(&self) -> Option<String> {
+        let (start, end) = self.get_selection()?;
+        if start.0 == end.0 {
+            return Some(self.lines[start.0][start.1..end.1].to_string());
+        }
+        let mut text = self.lines[start.0][start.1..].to_string();
+        for line in &self.lines[start.0 + 1..end.0] {
+            text += &("\n".to_string() + line);
+        }
+        return Some(text + "\n" + &self.lines[end.0][..end.1]);
+    }
+
+    pub fn cut_selection(&mut self) -> Option<String> {
+        let text = self.get_selected_text()?;
+        self.save_undo(EditKind::Other);
+        self.delete_selection();
+        return Some(text);
+    }
+
+    fn get_width(&self) -> usize {
+        return match self.rect {
+            Some(rect) => rect.width as usize,
+            None => 0,
+        };
+    }
+
+    fn get_height(&self) -> usize {
+        return match self.rect {
+            Some(rect) => rect.height.max(1) as usize,
+            None => usize::MAX,
+        };
+    }
+
+    fn get_visual_rows(&self) -> Vec<VisualRow> {
+        return wrap_rows(self, self.get_width());
+    }
+
+    fn get_visual_position(&self, rows: &[VisualRow], position: Position) -> (usize, usize) {
+        for (index, (row, start, end)) in rows.iter().enumerate() {
+            let is_last = rows.get(index + 1).map(|next| next.0) != Some(*row);
+            if *row == position.0 && *start <= position.1 && (position.1 < *end || is_last) {
+                return (index, str_width(&self.lines[*row][*start..position.1]));
+            }
+        }
+        return (0, 0);
+    }
+
+    fn get_position_at(&self, rows: &[VisualRow], index: usize, column: usize) -> Position {
+        let index = index.min(rows.len().saturating_sub(1));
+        let Some((row, start, end)) = rows.get(index).copied() else {
+            return (0, 0);
+        };
+        let is_last = rows.get(index + 1).map(|next| next.0) != Some(row);
+        let line = &self.lines[row];
+        let segment = graphemes(&line[start..end]);
+        let mut position = start;
+        let mut width = 0;
+        for (count, grapheme) in segment.iter().enumerate() {
+            let grapheme_columns = grapheme_width(grapheme);
+            if width + grapheme_columns > column || (!is_last && count + 1 == segment.len()) {
+                break;
+            }
+            width += grapheme_columns;
+            position += grapheme.len();
+        }
+        return (row, position);
+    }
+
+    fn ensure_cursor_visible(&mut self) {
+        let rows = self.get_visual_rows();
+        let (index, _) = self.get_visual_position(&rows, self.cursor);
+        let height = self.get_height();
+        if index < self.scroll {
+            self.scroll = index;
+        } else if index >= self.scroll.saturating_add(height) {
+            self.scroll = index + 1 - height;
+        }
+    }
+
+    fn scroll_by(&mut self, lines: isize) {
+        let rows = self.get_visual_rows().len();
+        let max_scroll = rows.saturating_sub(self.get_height());
+        self.scroll = self.scroll.saturating_add_signed(lines).min(max_scroll);
+    }
+
+    fn move_to(&mut self, position: Position, is_selecting: bool) {
+        match is_selecting {
+            true => self.anchor = Some(self.anchor.unwrap_or(self.cursor)),
+            false => self.anchor = None,
+        }
+        self.cursor = position;
+        self.last_edit = None;
+        self.ensure_cursor_visible();
+    }
+
+    fn get_previous_position(&self) -> Position {
+        let (row, column) = self.cursor;
+        if column == 0 {
+            return match row {
+                0 => (0, 0),
+                _ => (row - 1, self.lines[row - 1].len()),
+            };
+        }
+        let previous = graphemes(&self.lines[row][..column])
+            .last()
+            .map(|grapheme| grapheme.len())
+            .unwrap_or(0);
+        return (row, column - previous);
+    }
+
+    fn get_next_position(&self) -> Position {
+        let (row, column) = self.cursor;
+        let line = &self.lines[row];
+        if column >= line.len() {
+            return match row + 1 < self.lines.len() {
+                true => (row + 1, 0),
+                false => (row, column),
+            };
+        }
+        let next = graphemes(&line[column..])
+            .first()
+            .map(|grapheme| grapheme.len())
+            .unwrap_or(0);
+        return (row, column + next);
+    }
+
+    fn move_vertically(&mut self, offset: isize) {
+        let rows = self.get_visual_rows();
+        let (index, column) = self.get_visual_position(&rows, self.cursor);
+        let column = *self.preferred_column.get_or_insert(column);
+        let target = index
+            .saturating_add_signed(offset)
+            .min(rows.len().saturating_sub(1));
+        let position = self.get_position_at(&rows, target, column);
+        self.move_to(position, false);
+        self.preferred_column = Some(column);
+    }
+
+    fn get_row_bounds(&self) -> (Position, Position) {
+        let rows = self.get_visual_rows();
+        let (index, _) = self.get_visual_position(&rows, self.cursor);
+        let (row, start, _) = rows[index];
+        return ((row, start), self.get_position_at(&rows, index, usize::MAX));
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        return Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+        };
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.lines = snapshot.lines;
+        self.cursor = snapshot.cursor;
+        self.anchor = None;
+        self.last_edit = None;
+        self.ensure_cursor_visible();
+    }
+
+    fn save_undo(&mut self, kind: EditKind) {
+        if kind != EditKind::Other && self.last_edit == Some(kind) {
+            return;
+        }
+        self.undo_stack.push(self.snapshot());
+        if self.undo_stack.len() > MAX_UNDO {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+        self.last_edit = Some(kind);
+    }
+
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo_stack.pop() else {
+            return false;
+        };
+        self.redo_stack.push(self.snapshot());
+        self.restore(snapshot);
+        return true;
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo_stack.pop() else {
+            return false;
+        };
+        self.undo_stack.push(self.snapshot());
+        self.restore(snapshot);
+        return true;
+    }
+
+    fn delete_range(&mut self, start: Position, end: Position) {
+        let tail = self.lines[end.0][end.1..].to_string();
+        self.lines[start.0].truncate(start.1);
+        self.lines[start.0] += &tail;
+        self.lines.drain(start.0 + 1..=end.0);
+        self.cursor = start;
+        self.anchor = None;
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some((start, end)) = self.get_selection() else {
+            self.anchor = None;
+            return false;
+        };
+        self.delete_range(start, end);
+        return true;
+    }
+
+    fn insert_text(&mut self, text: &str) {
+        self.delete_selection();
+        let (row, column) = self.cursor;
+        let tail = self.lines[row].split_off(column);
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let mut inserted = text.split('\n').map(|line| line.replace('\t', TAB));
+        self.lines[row] += &inserted.next().unwrap_or_default();
+        let mut last_row = row;
+        for line in inserted {
+            last_row += 1;
+            self.lines.insert(last_row, line);
+        }
+        self.cursor = (last_row, self.lines[last_row].len());
+        self.lines[last_row] += &tail;
+    }
+
+    fn get_insert_kind(&self, text: &str) -> EditKind {
+        if self.is_pasting {
+            return EditKind::Paste;
+        }
+        return match text.trim().is_empty() || self.anchor.is_some() {
+            true => EditKind::Other,
+            false => EditKind::Insert,
+        };
+    }
+
+    pub fn insert(&mut self, text: &str) {
+        self.save_undo(self.get_insert_kind(text));
+        self.insert_text(text);
+        self.preferred_column = None;
+        self.ensure_cursor_visible();
+    }
+
+    pub fn paste(&mut self, text: &str) {
+        self.save_undo(EditKind::Other);
+        self.insert_text(text);
+        self.last_edit = None;
+        self.preferred_column = None;
+        self.ensure_cursor_visible();
+    }
+
+    fn delete_backwards(&mut self) {
+        self.save_undo(EditKind::Delete);
+        if !self.delete_selection() {
+            let end = self.cursor;
+            self.delete_range(self.get_previous_position(), end);
+        }
+        self.last_edit = Some(EditKind::Delete);
+        self.ensure_cursor_visible();
+    }
+
+    fn delete_forwards(&mut self) {
+        self.save_undo(EditKind::Delete);
+        if !self.delete_selection() {
+            let start = self.cursor;
+            self.delete_range(start, self.get_next_position());
+        }
+        self.last_edit = Some(EditKind::Delete);
+        self.ensure_cursor_visible();
+    }
+
+    fn get_mouse_position(&self, x: u16, y: u16) -> Option<Position> {
+        let rect = self.rect?;
+        let rows = self.get_visual_rows();
+        let index = match y < rect.y {
+            true => self.scroll.saturating_sub(1),
+            false => self.scroll + (y - rect.y) as usize,
+        };
+        let column = x.saturating_sub(rect.x) as usize;
+        return Some(self.get_position_at(&rows, index, column));
+    }
+
+    pub fn handle_event(&mut self, event: TuiEvents) -> bool {
+        if !matches!(event, TuiEvents::UpArrow | TuiEvents::DownArrow) {
+            self.preferred_column = None;
+        }
+        match event {
+            TuiEvents::PasteStart => {
+                self.save_undo(EditKind::Other);
+                self.last_edit = Some(EditKind::Paste);
+                self.is_pasting = true;
+            }
+            TuiEvents::PasteEnd => {
+                self.is_pasting = false;
+                self.last_edit = None;
+            }
+            TuiEvents::AsciiReadable(c) | TuiEvents::Other(c) if !c.is_control() => {
+                self.insert(&c.to_string())
+            }
+            TuiEvents::Space => self.insert(" "),
+            TuiEvents::Enter => self.insert("\n"),
+            TuiEvents::Tab => self.insert(TAB),
+            _ if self.is_pasting => return false,
+            TuiEvents::Backspace => self.delete_backwards(),
+            TuiEvents::Delete => self.delete_forwards(),
+            TuiEvents::Control('Z') => _ = self.undo(),
+            TuiEvents::Control('Y') => _ = self.redo(),
+            TuiEvents::LeftArrow => match self.get_selection() {
+                Some((start, _)) => self.move_to(start, false),
+                None => self.move_to(self.get_previous_position(), false),
+            },
+            TuiEvents::RightArrow => match self.get_selection() {
+                Some((_, end)) => self.move_to(end, false),
+                None => self.move_to(self.get_next_position(), false),
+            },
+            TuiEvents::UpArrow => self.move_vertically(-1),
+            TuiEvents::DownArrow => self.move_vertically(1),
+            TuiEvents::PageUp => self.move_vertically(-(self.get_height().min(1 << 16) as isize)),
+            TuiEvents::PageDown => self.move_vertically(self.get_height().min(1 << 16) as isize),
+            TuiEvents::Home | TuiEvents::Control('A') => {
+                self.move_to(self.get_row_bounds().0, false)
+            }
+            TuiEvents::End | TuiEvents::Control('E') => {
+                self.move_to(self.get_row_bounds().1, false)
+            }
+            TuiEvents::Escape => self.anchor = None,
+            TuiEvents::LeftClick((x, y)) => match self.rect {
+                Some(rect) if rect.contains(x, y) => {
+                    let Some(position) = self.get_mouse_position(x, y) else {
+                        return false;
+                    };
+                    self.move_to(position, false);
+                    self.anchor = Some(position);
+                }
+                _ => return false,
+            },
+            TuiEvents::LeftDrag((x, y)) if self.anchor.is_some() => {
+                let Some(position) = self.get_mouse_position(x, y) else {
+                    return false;
+                };
+                self.move_to(position, true);
+            }
+            TuiEvents::ScrollUp((x, y)) | TuiEvents::ScrollDown((x, y))
+                if self.rect.is_some_and(|rect| rect.contains(x, y)) =>
+            {
+                match event {
+                    TuiEvents::ScrollUp(_) => self.scroll_by(-(SCROLL_LINES as isize)),
+                    _ => self.scroll_by(SCROLL_LINES as isize),
+                }
+            }
+            _ => return false,
+        }
+        return true;
+    }
+
+    pub fn get_cursor_screen_position(&self) -> Option<(u16, u16)> {
+        let rect = self.rect?;
+        let rows = self.get_visual_rows();
+        let (index, column) = self.get_visual_position(&rows, self.cursor);
+        if index < self.scroll || index >= self.scroll + rect.height as usize {
+            return None;
+        }
+        let column = column.min(rect.width.saturating_sub(1) as usize);
+        return Some((
+            rect.x + column as u16,
+            rect.y + (index - self.scroll) as u16,
+        ));
+    }
+
+    pub fn draw(&mut self, tui_terminal: &mut TuiTerminal, rect: Rect) {
+        self.set_rect(rect);
+        tui_terminal.draw_widget(self, rect);
+        if let Some((x, y)) = self.get_cursor_screen_position() {
+            tui_terminal.set_cursor_position(x, y);
+        }
+    }
+}
+
+impl Widget for TextArea {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>> {
+        let rows = wrap_rows(self, width as usize);
+        let selection = self.get_selection();
+        return rows
+            .iter()
+            .skip(self.scroll)
+            .take(height as usize)
+            .map(|(row, start, end)| {
+                let line = &self.lines[*row];
+                let (selected_start, selected_end) = match selection {
+                    Some((from, to)) if from.0 <= *row && *row <= to.0 => (
+                        match from.0 == *row {
+                            true => from.1.clamp(*start, *end),
+                            false => *start,
+                        },
+                        match to.0 == *row {
+                            true => to.1.clamp(*start, *end),
+                            false => *end,
+                        },
+                    ),
+                    _ => (*start, *start),
+                };
+                let mut spans: Vec<StringPlus> = Vec::new();
+                for (from, to, is_selected) in [
+                    (*start, selected_start, false),
+                    (selected_start, selected_end, true),
+                    (selected_end, *end, false),
+                ] {
+                    if from < to {
+                        let text = line[from..to].to_string();
+                        spans.push(match is_selected {
+                            true => text.set_role(ThemeRole::Selection),
+                            false => text.into(),
+                        });
+                    }
+                }
+                let used: usize = spans.iter().map(|span| span.width()).sum();
+                spans.push(" ".repeat((width as usize).saturating_sub(used)).into());
+                spans
+            })
+            .collect();
+    }
+}
+
+fn wrap_rows(text_area: &TextArea, width: usize) -> Vec<VisualRow> {
+    let mut rows: Vec<VisualRow> = Vec::new();
+    for (row, line) in text_area.lines.iter().enumerate() {
+        for (start, end) in wrap_line(line, width) {
+            rows.push((row, start, end));
+        }
+    }
+    return rows;
+}
@@ -0,0 +1,133 @@
+use crate::{
+    rect::Rect,
+    text_area::{wrap_line, TextArea},
+    theme::ThemeRole,
+    tui_events::TuiEvents,
+    tui_io::input_parser::parse_sequence,
+    widget::Widget,
+};
+
+fn type_sequence(text_area: &mut TextArea, sequence: &str) {
+    for event in parse_sequence(sequence, true) {
+        text_area.handle_event(event);
+    }
+}
+
+#[test]
+fn test_wrap_line() -> Result<(), String> {
+    let cases = [
+        ("hello world", 8, vec![(0, 6), (6, 11)]),
+        ("abcdefghij", 4, vec![(0, 4), (4, 8), (8, 10)]),
+        ("漢字漢字", 5, vec![(0, 6), (6, 12)]),
+        ("", 4, vec![(0, 0)]),
+    ];
+    for (line, width, expected) in cases {
+        if wrap_line(line, width) != expected {
+            Err(format!("{:?}: {:?}", line, wrap_line(line, width)))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_editing_and_undo() -> Result<(), String> {
+    let mut text_area = TextArea::new("");
+    type_sequence(&mut text_area, "fix bug\rdetails");
+    if text_area.get_text() != "fix bug\ndetails" || text_area.get_cursor() != (1, 7) {
+        Err(format!("{:?}", text_area.get_text()))?;
+    }
+    type_sequence(&mut text_area, "\x1b[A\x7f\x7f\x7f");
+    if text_area.get_text() != "fix \ndetails" {
+        Err(format!("Backspace: {:?}", text_area.get_text()))?;
+    }
+    type_sequence(&mut text_area, "\x1a");
+    if text_area.get_text() != "fix bug\ndetails" {
+        Err(format!("Undo of a delete run: {:?}", text_area.get_text()))?;
+    }
+    type_sequence(&mut text_area, "\x1a");
+    if text_area.get_text() != "fix bug\n" {
+        Err(format!("Undo of typed word: {:?}", text_area.get_text()))?;
+    }
+    type_sequence(&mut text_area, "\x19\x19");
+    if text_area.get_text() != "fix \ndetails" {
+        Err(format!("Redo: {:?}", text_area.get_text()))?;
+    }
+    type_sequence(&mut text_area, "\x1b[F\x1b[3~");
+    if text_area.get_text() != "fix details" {
+        Err(format!("Delete joining lines: {:?}", text_area.get_text()))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_bracketed_paste() -> Result<(), String> {
+    let mut text_area = TextArea::new("ab");
+    text_area.set_cursor(0, 1);
+    type_sequence(&mut text_area, "\x1b[200~one\rtwo\x1a\x1b[201~");
+    if text_area.get_text() != "aone\ntwob" || text_area.get_cursor() != (1, 3) {
+        Err(format!(
+            "{:?} {:?}",
+            text_area.get_text(),
+            text_area.get_cursor()
+        ))?;
+    }
+    type_sequence(&mut text_area, "\x1a");
+    if text_area.get_text() != "ab" {
+        Err(format!(
+            "Paste not undone as one step: {:?}",
+            text_area.get_text()
+        ))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_soft_wrap_and_scroll() -> Result<(), String> {
+    let mut text_area = TextArea::new("one two three\nfour\nfive\nsix");
+    text_area.set_rect(Rect::new(5, 3, 8, 3));
+    let text: Vec<String> = text_area
+        .render(8, 3)
+        .iter()
+        .map(|line| line.iter().map(|span| span.to_string()).collect())
+        .collect();
+    if text != ["one two ", "three   ", "four    "] {
+        Err(format!("{:?}", text))?;
+    }
+    type_sequence(&mut text_area, "\x1b[C\x1b[B\x1b[B");
+    if text_area.get_cursor() != (1, 1) || text_area.get_cursor_screen_position() != Some((6, 5)) {
+        Err(format!("Visual down: {:?}", text_area.get_cursor()))?;
+    }
+    type_sequence(&mut text_area, "\x1b[B\x1b[B");
+    if text_area.get_scroll() != 2 || text_area.get_cursor() != (3, 1) {
+        Err(format!(
+            "Scroll {} cursor {:?}",
+            text_area.get_scroll(),
+            text_area.get_cursor()
+        ))?;
+    }
+    text_area.handle_event(TuiEvents::ScrollUp((6, 4)));
+    if text_area.get_scroll() != 0 {
+        Err(format!("Mouse wheel: {}", text_area.get_scroll()))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_mouse_selection() -> Result<(), String> {
+    let mut text_area = TextArea::new("hello world\nsecond line");
+    text_area.set_rect(Rect::new(1, 1, 20, 5));
+    text_area.handle_event(TuiEvents::LeftClick((7, 1)));
+    text_area.handle_event(TuiEvents::LeftDrag((7, 2)));
+    if text_area.get_selected_text().as_deref() != Some("world\nsecond") {
+        Err(format!("{:?}", text_area.get_selected_text()))?;
+    }
+    let lines = text_area.render(20, 2);
+    if lines[0][1].to_string() != "world" || lines[0][1].get_role() != Some(ThemeRole::Selection) {
+        Err(format!("Selection not highlighted: {:?}", lines[0]))?;
+    }
+    type_sequence(&mut text_area, "X");
+    if text_area.get_text() != "hello X line" {
+        Err(format!("Typing over selection: {:?}", text_area.get_text()))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,168 @@
+use crate::{
+    string_plus::StringPlus,
+    unicode_width::{grapheme_width, graphemes},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WrapOptions {
+    pub width: usize,
+    pub initial_indent: usize,
+    pub hanging_indent: usize,
+}
+
+impl WrapOptions {
+    pub fn new(width: usize) -> WrapOptions {
+        return WrapOptions {
+            width,
+            initial_indent: 0,
+            hanging_indent: 0,
+        };
+    }
+
+    pub fn initial_indent(mut self, initial_indent: usize) -> WrapOptions {
+        self.initial_indent = initial_indent;
+        return self;
+    }
+
+    pub fn hanging_indent(mut self, hanging_indent: usize) -> WrapOptions {
+        self.hanging_indent = hanging_indent;
+        return self;
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Piece<'a> {
+    span: usize,
+    text: &'a str,
+    width: usize,
+}
+
+struct LineBuilder<'a, 'b> {
+    spans: &'b [StringPlus],
+    options: WrapOptions,
+    lines: Vec<Vec<StringPlus>>,
+    pieces: Vec<Piece<'a>>,
+    width: usize,
+}
+
+impl<'a, 'b> LineBuilder<'a, 'b> {
+    fn available_width(&self) -> usize {
+        let indent = match self.lines.len() {
+            0 => self.options.initial_indent,
+            _ => self.options.hanging_indent,
+        };
+        return self.options.width.saturating_sub(indent).max(1);
+    }
+
+    fn push(&mut self, piece: Piece<'a>) {
+        self.width += piece.width;
+        self.pieces.push(piece);
+    }
+
+    fn finish_line(&mut self) {
+        let indent = match self.lines.len() {
+            0 => self.options.initial_indent,
+            _ => self.options.hanging_indent,
+        };
+        let mut line: Vec<StringPlus> = Vec::new();
+        if indent > 0 {
+            line.push(" ".repeat(indent).into());
+        }
+        let mut current: Option<(usize, String)> = None;
+        for piece in self.pieces.drain(..) {
+            match &mut current {
+                Some((span, text)) if *span == piece.span => *text += piece.text,
+                _ => {
+                    if let Some((span, text)) = current.take() {
+                        line.push(self.spans[span].with_string(text));
+                    }
+                    current = Some((piece.span, piece.text.to_string()));
+                }
+            }
+        }
+        if let Some((span, text)) = current {
+            line.push(self.spans[span].with_string(text));
+        }
+        self.lines.push(line);
+        self.width = 0;
+    }
+
+    fn push_word(&mut self, spaces: Vec<Piece<'a>>, word: Vec<Piece<'a>>) {
+        if word.is_empty() {
+            return;
+        }
+        let space_width: usize = spaces.iter().map(|piece| piece.width).sum();
+        let word_width: usize = word.iter().map(|piece| piece.width).sum();
+        if self.width + space_width + word_width <= self.available_width() {
+            for piece in spaces.into_iter().chain(word) {
+                self.push(piece);
+            }
+            return;
+        }
+        let next_width = self
+            .options
+            .width
+            .saturating_sub(self.options.hanging_indent)
+            .max(1);
+        if word_width > next_width && self.width + space_width < self.available_width() {
+            for piece in spaces {
+                self.push(piece);
+            }
+        } else if !self.pieces.is_empty() {
+            self.finish_line();
+        }
+        for piece in word {
+            if self.width + piece.width > self.available_width() && !self.pieces.is_empty() {
+                self.finish_line();
+            }
+            self.push(piece);
+        }
+    }
+}
+
+pub fn wrap(spans: &[StringPlus], options: WrapOptions) -> Vec<Vec<StringPlus>> {
+    let strings: Vec<String> = spans.iter().map(|span| span.into()).collect();
+    let mut builder = LineBuilder {
+        spans,
+        options,
+        lines: Vec::new(),
+        pieces: Vec::new(),
+        width: 0,
+    };
+    let mut spaces: Vec<Piece> = Vec::new();
+    let mut word: Vec<Piece> = Vec::new();
+    for (span, string) in strings.iter().enumerate() {
+        for grapheme in graphemes(string) {
+            match grapheme {
+                "\n" | "\r\n" => {
+                    builder.push_word(spaces, word);
+                    spaces = Vec::new();
+                    word = Vec::new();
+                    builder.finish_line();
+                }
+                " " | "\t" => {
+                    if !word.is_empty() {
+                        builder.push_word(spaces, word);
+                        spaces = Vec::new();
+                        word = Vec::new();
+                    }
+                    spaces.push(Piece {
+                        span,
+                        text: " ",
+                        width: 1,
+                    });
+                }
+                _ => word.push(Piece {
+                    span,
+                    text: grapheme,
+                    width: grapheme_width(grapheme),
+                }),
+            }
+        }
+    }
+    builder.push_word(spaces, word);
+    if !builder.pieces.is_empty() || builder.lines.is_empty() {
+        builder.finish_line();
+    }
+    return builder.lines;
+}
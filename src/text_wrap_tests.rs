@@ -0,0 +1,61 @@
+use crate::{
+    string_plus::{StringPlus, StringPlusTrait},
+    text_wrap::{wrap, WrapOptions},
+    tui_enums::Color,
+};
+
+fn line_strings(lines: &[Vec<StringPlus>]) -> Vec<String> {
+    return lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.to_string()).collect())
+        .collect();
+}
+
+#[test]
+fn test_wrap_words() -> Result<(), String> {
+    let lines = StringPlus::from("the quick brown fox jumps").wrap(WrapOptions::new(10));
+    let expected = vec!["the quick", "brown fox", "jumps"];
+    if line_strings(&lines) != expected {
+        Err(format!("{:?} != {:?}", line_strings(&lines), expected))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_wrap_preserves_styles() -> Result<(), String> {
+    let spans = vec![
+        "hello ".set_font_color(Color::Red),
+        "wonderful world".set_font_color(Color::Blue),
+    ];
+    let lines = wrap(&spans, WrapOptions::new(16));
+    let colors: Vec<Vec<Color>> = lines
+        .iter()
+        .map(|line| line.iter().map(|span| span.get_font_color()).collect())
+        .collect();
+    let expected_colors = vec![vec![Color::Red, Color::Blue], vec![Color::Blue]];
+    if line_strings(&lines) != vec!["hello wonderful", "world"] || colors != expected_colors {
+        Err(format!("{:?}", lines))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_wrap_hanging_indent_and_long_words() -> Result<(), String> {
+    let options = WrapOptions::new(6).hanging_indent(2);
+    let lines = StringPlus::from("- abcdefghij").wrap(options);
+    let expected = vec!["- abcd", "  efgh", "  ij"];
+    if line_strings(&lines) != expected {
+        Err(format!("{:?} != {:?}", line_strings(&lines), expected))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_wrap_hard_breaks() -> Result<(), String> {
+    let lines = StringPlus::from("one\n\ntwo").wrap(WrapOptions::new(10));
+    let expected = vec!["one", "", "two"];
+    if line_strings(&lines) != expected {
+        Err(format!("{:?} != {:?}", line_strings(&lines), expected))?;
+    }
+    return Ok(());
+}
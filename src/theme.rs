@@ -0,0 +1,246 @@
+use std::{collections::HashMap, error::Error, fs, path::Path, str::FromStr};
+
+use crate::{
+    font_settings::FontSettings,
+    tui_enums::{Color, ThreeBool, UnderlineStyle},
+    tui_errors::ThemeParseError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ThemeRole {
+    Error,
+    Warning,
+    Success,
+    Muted,
+    Accent,
+    Selection,
+    Border,
+    Title,
+}
+
+impl ThemeRole {
+    pub const ALL: [ThemeRole; 8] = [
+        ThemeRole::Error,
+        ThemeRole::Warning,
+        ThemeRole::Success,
+        ThemeRole::Muted,
+        ThemeRole::Accent,
+        ThemeRole::Selection,
+        ThemeRole::Border,
+        ThemeRole::Title,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        return match self {
+            ThemeRole::Error => "error",
+            ThemeRole::Warning => "warning",
+            ThemeRole::Success => "success",
+            ThemeRole::Muted => "muted",
+            ThemeRole::Accent => "accent",
+            ThemeRole::Selection => "selection",
+            ThemeRole::Border => "border",
+            ThemeRole::Title => "title",
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    name: String,
+    styles: HashMap<ThemeRole, FontSettings>,
+}
+
+fn font_color(color: Color) -> FontSettings {
+    return FontSettings {
+        font_color: color,
+        ..Default::default()
+    };
+}
+
+fn bold(mut font_settings: FontSettings) -> FontSettings {
+    font_settings.is_bold = ThreeBool::True;
+    return font_settings;
+}
+
+fn background_color(color: Color) -> FontSettings {
+    return FontSettings {
+        background_color: color,
+        ..Default::default()
+    };
+}
+
+impl Theme {
+    pub fn new(name: &str) -> Theme {
+        return Theme {
+            name: name.to_string(),
+            styles: HashMap::new(),
+        };
+    }
+
+    pub fn dark() -> Theme {
+        return Theme::new("dark")
+            .with_style(ThemeRole::Error, font_color(Color::BrightRed))
+            .with_style(ThemeRole::Warning, font_color(Color::BrightYellow))
+            .with_style(ThemeRole::Success, font_color(Color::BrightGreen))
+            .with_style(ThemeRole::Muted, font_color(Color::CC256(244)))
+            .with_style(ThemeRole::Accent, font_color(Color::BrightCyan))
+            .with_style(ThemeRole::Selection, background_color(Color::CC256(238)))
+            .with_style(ThemeRole::Border, font_color(Color::CC256(240)))
+            .with_style(ThemeRole::Title, bold(font_color(Color::BrightWhite)));
+    }
+
+    pub fn light() -> Theme {
+        return Theme::new("light")
+            .with_style(ThemeRole::Error, font_color(Color::Red))
+            .with_style(ThemeRole::Warning, font_color(Color::CC256(130)))
+            .with_style(ThemeRole::Success, font_color(Color::Green))
+            .with_style(ThemeRole::Muted, font_color(Color::CC256(245)))
+            .with_style(ThemeRole::Accent, font_color(Color::Blue))
+            .with_style(ThemeRole::Selection, background_color(Color::CC256(252)))
+            .with_style(ThemeRole::Border, font_color(Color::CC256(250)))
+            .with_style(ThemeRole::Title, bold(font_color(Color::Black)));
+    }
+
+    pub fn high_contrast() -> Theme {
+        let selection = FontSettings {
+            is_inverted: ThreeBool::True,
+            ..Default::default()
+        };
+        let mut title = bold(font_color(Color::BrightWhite));
+        title.is_underlined = ThreeBool::True;
+        return Theme::new("high-contrast")
+            .with_style(ThemeRole::Error, bold(font_color(Color::BrightRed)))
+            .with_style(ThemeRole::Warning, bold(font_color(Color::BrightYellow)))
+            .with_style(ThemeRole::Success, bold(font_color(Color::BrightGreen)))
+            .with_style(ThemeRole::Muted, font_color(Color::White))
+            .with_style(ThemeRole::Accent, bold(font_color(Color::BrightCyan)))
+            .with_style(ThemeRole::Selection, selection)
+            .with_style(ThemeRole::Border, font_color(Color::BrightWhite))
+            .with_style(ThemeRole::Title, title);
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Theme, Box<dyn Error>> {
+        return Ok(fs::read_to_string(path)?.parse()?);
+    }
+
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn get_style(&self, role: ThemeRole) -> FontSettings {
+        return self.styles.get(&role).copied().unwrap_or_default();
+    }
+
+    pub fn set_style(&mut self, role: ThemeRole, font_settings: FontSettings) {
+        self.styles.insert(role, font_settings);
+    }
+
+    pub fn with_style(mut self, role: ThemeRole, font_settings: FontSettings) -> Theme {
+        self.set_style(role, font_settings);
+        return self;
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        return Theme::dark();
+    }
+}
+
+fn parse_three_bool(value: &str) -> Option<ThreeBool> {
+    return match value {
+        "true" | "yes" | "on" => Some(ThreeBool::True),
+        "false" | "no" | "off" => Some(ThreeBool::False),
+        "default" => Some(ThreeBool::Default),
+        _ => None,
+    };
+}
+
+fn parse_underline_style(value: &str) -> Option<UnderlineStyle> {
+    return match value {
+        "single" => Some(UnderlineStyle::Single),
+        "double" => Some(UnderlineStyle::Double),
+        "curly" => Some(UnderlineStyle::Curly),
+        "default" => Some(UnderlineStyle::Default),
+        _ => None,
+    };
+}
+
+fn apply_setting(font_settings: &mut FontSettings, key: &str, value: &str) -> Option<()> {
+    let flag = match key {
+        "font_color" | "foreground" | "fg" => {
+            font_settings.font_color = value.parse().ok()?;
+            return Some(());
+        }
+        "background_color" | "background" | "bg" => {
+            font_settings.background_color = value.parse().ok()?;
+            return Some(());
+        }
+        "underline_color" => {
+            font_settings.underline_color = value.parse().ok()?;
+            return Some(());
+        }
+        "underline_style" => {
+            font_settings.underline_style = parse_underline_style(value)?;
+            return Some(());
+        }
+        "bold" => &mut font_settings.is_bold,
+        "dim" => &mut font_settings.is_dim,
+        "italics" | "italic" => &mut font_settings.is_italics,
+        "underlined" | "underline" => &mut font_settings.is_underlined,
+        "inverted" => &mut font_settings.is_inverted,
+        "blinking" => &mut font_settings.is_blinking,
+        "strikethrough" => &mut font_settings.is_strikethrough,
+        "overlined" => &mut font_settings.is_overlined,
+        "hidden" => &mut font_settings.is_hidden,
+        _ => return None,
+    };
+    *flag = parse_three_bool(value)?;
+    return Some(());
+}
+
+impl FromStr for Theme {
+    type Err = ThemeParseError;
+
+    fn from_str(string: &str) -> Result<Theme, ThemeParseError> {
+        let mut theme = Theme::new("custom");
+        let mut role: Option<ThemeRole> = None;
+        for (index, line) in string.lines().enumerate() {
+            let error = |message: &str| ThemeParseError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[') {
+                let section = section
+                    .strip_suffix(']')
+                    .ok_or(error("Unterminated Section"))?
+                    .trim()
+                    .to_ascii_lowercase();
+                let section_role = ThemeRole::ALL
+                    .into_iter()
+                    .find(|role| role.get_name() == section)
+                    .ok_or(error("Unknown Role"))?;
+                theme.styles.entry(section_role).or_default();
+                role = Some(section_role);
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or(error("Expected key = value"))?;
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim().trim_matches('"');
+            match role {
+                None if key == "name" => theme.name = value.to_string(),
+                None => Err(error("Setting Outside Of Section"))?,
+                Some(role) => {
+                    let font_settings = theme.styles.entry(role).or_default();
+                    apply_setting(font_settings, &key, &value.to_ascii_lowercase())
+                        .ok_or(error("Invalid Setting"))?;
+                }
+            }
+        }
+        return Ok(theme);
+    }
+}
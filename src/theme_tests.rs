@@ -0,0 +1,64 @@
+use crate::{
+    font_settings::FontSettings,
+    theme::{Theme, ThemeRole},
+    tui_enums::{Color, ThreeBool, UnderlineStyle},
+};
+
+#[test]
+fn test_parse_theme() -> Result<(), String> {
+    let theme: Theme = "
+        # Catppuccin inspired
+        name = mocha
+        [error]
+        font_color = #f38ba8
+        bold = true
+        [muted]
+        fg = ansi(240)
+        underline_style = curly
+    "
+    .parse()
+    .map_err(|error| format!("{}", error))?;
+    let error = theme.get_style(ThemeRole::Error);
+    let muted = theme.get_style(ThemeRole::Muted);
+    if theme.get_name() != "mocha"
+        || error.font_color != Color::RGB(0xf3, 0x8b, 0xa8)
+        || error.is_bold != ThreeBool::True
+        || muted.font_color != Color::CC256(240)
+        || muted.underline_style != UnderlineStyle::Curly
+        || theme.get_style(ThemeRole::Title) != FontSettings::default()
+    {
+        Err(format!("{:?}", theme))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_parse_theme_errors() -> Result<(), String> {
+    let cases = [
+        ("[unknown]", 1),
+        ("[error]\nbold = maybe", 2),
+        ("bold = true", 1),
+        ("[error]\n\nfont_color", 3),
+    ];
+    for (string, line) in cases {
+        match string.parse::<Theme>() {
+            Err(error) if error.line == line => {}
+            result => Err(format!("{:?}: {:?}", string, result))?,
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_overlay_font_settings() -> Result<(), String> {
+    let base = Theme::dark().get_style(ThemeRole::Title);
+    let top = FontSettings {
+        font_color: Color::Red,
+        ..Default::default()
+    };
+    let result = base.overlay(&top);
+    if result.font_color != Color::Red || result.is_bold != ThreeBool::True {
+        Err(format!("{:?}", result))?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,238 @@
+const TMUX_CHUNK_LENGTH: usize = 4096;
+const SCREEN_CHUNK_LENGTH: usize = 76;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TerminalKind {
+    Kitty,
+    ITerm2,
+    WezTerm,
+    Ghostty,
+    Foot,
+    Alacritty,
+    Konsole,
+    Vte,
+    VsCode,
+    WindowsTerminal,
+    XTerm,
+    Unknown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Multiplexer {
+    Tmux,
+    Screen,
+    None,
+}
+
+impl Multiplexer {
+    pub fn wrap_passthrough(&self, prefix: &str, body: &str, suffix: &str) -> String {
+        let chunk_length = match self {
+            Multiplexer::Tmux => TMUX_CHUNK_LENGTH,
+            Multiplexer::Screen => SCREEN_CHUNK_LENGTH,
+            Multiplexer::None => return prefix.to_string() + body + suffix,
+        };
+        let body: Vec<char> = body.chars().collect();
+        let mut pieces: Vec<String> = body
+            .chunks(chunk_length)
+            .map(|chunk| chunk.iter().collect())
+            .collect();
+        if pieces.is_empty() {
+            pieces.push(String::new());
+        }
+        pieces[0].insert_str(0, prefix);
+        if let Some(last) = pieces.last_mut() {
+            last.push_str(suffix);
+        }
+        return pieces
+            .iter()
+            .map(|piece| match self {
+                Multiplexer::Tmux => {
+                    "\x1bPtmux;".to_string() + &piece.replace('\x1b', "\x1b\x1b") + "\x1b\\"
+                }
+                _ => "\x1bP".to_string() + piece + "\x1b\\",
+            })
+            .collect();
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NotificationProtocol {
+    Osc9,
+    Osc777,
+    Kitty,
+    None,
+}
+
+fn sanitize(text: &str, forbidden: &[char]) -> String {
+    return text
+        .chars()
+        .filter(|c| !c.is_control() && !forbidden.contains(c))
+        .collect();
+}
+
+impl NotificationProtocol {
+    pub fn get_sequence(&self, title: &str, body: &str, id: u32) -> Option<String> {
+        return match self {
+            NotificationProtocol::Osc9 => {
+                let message = match (title.is_empty(), body.is_empty()) {
+                    (false, false) => title.to_string() + ": " + body,
+                    _ => title.to_string() + body,
+                };
+                Some("\x1b]9;".to_string() + &sanitize(&message, &[]) + "\x07")
+            }
+            NotificationProtocol::Osc777 => Some(
+                "\x1b]777;notify;".to_string()
+                    + &sanitize(title, &[';'])
+                    + ";"
+                    + &sanitize(body, &[])
+                    + "\x07",
+            ),
+            NotificationProtocol::Kitty => {
+                let id = "i=".to_string() + &id.to_string();
+                Some(
+                    "\x1b]99;".to_string()
+                        + &id
+                        + ":d=0:p=title;"
+                        + &sanitize(title, &[])
+                        + "\x1b\\\x1b]99;"
+                        + &id
+                        + ":d=1:p=body;"
+                        + &sanitize(body, &[])
+                        + "\x1b\\",
+                )
+            }
+            NotificationProtocol::None => None,
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageProtocol {
+    Kitty,
+    ITerm2,
+    Sixel,
+    None,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capabilities {
+    pub terminal_kind: TerminalKind,
+    pub multiplexer: Multiplexer,
+    pub hyperlinks: bool,
+    pub left_right_margins: bool,
+    pub notifications: NotificationProtocol,
+    pub images: ImageProtocol,
+}
+
+impl Capabilities {
+    pub fn detect() -> Capabilities {
+        return Capabilities::from_env(|name| std::env::var(name).ok());
+    }
+
+    pub fn from_env<F: Fn(&str) -> Option<String>>(get_var: F) -> Capabilities {
+        let terminal_kind = detect_terminal_kind(&get_var);
+        let term = get_var("TERM").unwrap_or_default();
+        let hyperlinks = match terminal_kind {
+            _ if term == "dumb" => false,
+            TerminalKind::Vte => {
+                get_var("VTE_VERSION")
+                    .and_then(|version| version.parse::<u32>().ok())
+                    .unwrap_or(0)
+                    >= 5000
+            }
+            TerminalKind::XTerm | TerminalKind::Unknown => false,
+            _ => true,
+        };
+        let left_right_margins = matches!(
+            terminal_kind,
+            TerminalKind::Kitty
+                | TerminalKind::ITerm2
+                | TerminalKind::WezTerm
+                | TerminalKind::Ghostty
+                | TerminalKind::Foot
+                | TerminalKind::XTerm
+        ) && term != "dumb";
+        let multiplexer = match term.as_str() {
+            _ if get_var("TMUX").is_some() || term.starts_with("tmux") => Multiplexer::Tmux,
+            _ if get_var("STY").is_some() || term.starts_with("screen") => Multiplexer::Screen,
+            _ => Multiplexer::None,
+        };
+        let notifications = match terminal_kind {
+            _ if term == "dumb" => NotificationProtocol::None,
+            TerminalKind::Kitty => NotificationProtocol::Kitty,
+            TerminalKind::ITerm2 => NotificationProtocol::Osc9,
+            TerminalKind::WezTerm
+            | TerminalKind::Ghostty
+            | TerminalKind::Foot
+            | TerminalKind::Vte => NotificationProtocol::Osc777,
+            _ => NotificationProtocol::None,
+        };
+        let images = match terminal_kind {
+            _ if term == "dumb" => ImageProtocol::None,
+            TerminalKind::Kitty | TerminalKind::Ghostty => ImageProtocol::Kitty,
+            TerminalKind::ITerm2 | TerminalKind::WezTerm | TerminalKind::VsCode => {
+                ImageProtocol::ITerm2
+            }
+            TerminalKind::Foot | TerminalKind::Konsole | TerminalKind::WindowsTerminal => {
+                ImageProtocol::Sixel
+            }
+            _ => ImageProtocol::None,
+        };
+        return Capabilities {
+            terminal_kind,
+            multiplexer,
+            hyperlinks,
+            left_right_margins,
+            notifications,
+            images,
+        };
+    }
+
+    pub fn none() -> Capabilities {
+        return Capabilities {
+            terminal_kind: TerminalKind::Unknown,
+            multiplexer: Multiplexer::None,
+            hyperlinks: false,
+            left_right_margins: false,
+            notifications: NotificationProtocol::None,
+            images: ImageProtocol::None,
+        };
+    }
+}
+
+fn detect_terminal_kind<F: Fn(&str) -> Option<String>>(get_var: &F) -> TerminalKind {
+    let term = get_var("TERM").unwrap_or_default();
+    let term_program = get_var("TERM_PROGRAM").unwrap_or_default();
+    if get_var("KITTY_WINDOW_ID").is_some() || term == "xterm-kitty" {
+        return TerminalKind::Kitty;
+    }
+    match term_program.as_str() {
+        "iTerm.app" => return TerminalKind::ITerm2,
+        "WezTerm" => return TerminalKind::WezTerm,
+        "ghostty" => return TerminalKind::Ghostty,
+        "vscode" => return TerminalKind::VsCode,
+        _ => {}
+    }
+    if term == "xterm-ghostty" {
+        return TerminalKind::Ghostty;
+    }
+    if term.starts_with("foot") {
+        return TerminalKind::Foot;
+    }
+    if term == "alacritty" || get_var("ALACRITTY_WINDOW_ID").is_some() {
+        return TerminalKind::Alacritty;
+    }
+    if get_var("KONSOLE_VERSION").is_some() {
+        return TerminalKind::Konsole;
+    }
+    if get_var("VTE_VERSION").is_some() {
+        return TerminalKind::Vte;
+    }
+    if get_var("WT_SESSION").is_some() {
+        return TerminalKind::WindowsTerminal;
+    }
+    if term.starts_with("xterm") {
+        return TerminalKind::XTerm;
+    }
+    return TerminalKind::Unknown;
+}
@@ -0,0 +1,126 @@
+use crate::tui_capabilities::{Capabilities, Multiplexer, NotificationProtocol, TerminalKind};
+
+type EnvCase<'a> = (&'a [(&'a str, &'a str)], TerminalKind, bool);
+
+fn from_vars(vars: &[(&str, &str)]) -> Capabilities {
+    return Capabilities::from_env(|name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    });
+}
+
+#[test]
+fn test_detect_hyperlink_support() -> Result<(), String> {
+    let cases: [EnvCase; 5] = [
+        (&[("TERM", "xterm-kitty")], TerminalKind::Kitty, true),
+        (&[("TERM_PROGRAM", "iTerm.app")], TerminalKind::ITerm2, true),
+        (&[("VTE_VERSION", "6003")], TerminalKind::Vte, true),
+        (&[("VTE_VERSION", "4200")], TerminalKind::Vte, false),
+        (&[("TERM", "xterm-256color")], TerminalKind::XTerm, false),
+    ];
+    for (vars, terminal_kind, hyperlinks) in cases {
+        let capabilities = from_vars(vars);
+        if capabilities.terminal_kind != terminal_kind || capabilities.hyperlinks != hyperlinks {
+            Err(format!("{:?}: {:?}", vars, capabilities))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_dumb_terminal_has_no_hyperlinks() -> Result<(), String> {
+    let capabilities = from_vars(&[("TERM", "dumb"), ("WT_SESSION", "1")]);
+    if capabilities.hyperlinks {
+        Err(format!("{:?}", capabilities))?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_detect_left_right_margins() -> Result<(), String> {
+    let cases: [(&[(&str, &str)], bool); 4] = [
+        (&[("TERM", "xterm-256color")], true),
+        (&[("TERM", "foot")], true),
+        (&[("VTE_VERSION", "7600")], false),
+        (&[("WT_SESSION", "1")], false),
+    ];
+    for (vars, left_right_margins) in cases {
+        let capabilities = from_vars(vars);
+        if capabilities.left_right_margins != left_right_margins {
+            Err(format!("{:?}: {:?}", vars, capabilities))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_multiplexer_passthrough() -> Result<(), String> {
+    let tmux = from_vars(&[("TMUX", "/tmp/tmux-1000/default,1,0")]).multiplexer;
+    let screen = from_vars(&[("TERM", "screen-256color")]).multiplexer;
+    if tmux != Multiplexer::Tmux || screen != Multiplexer::Screen {
+        Err(format!("{:?} {:?}", tmux, screen))?;
+    }
+    let wrapped = tmux.wrap_passthrough("\x1b]52;c;", "Zm9v", "\x07");
+    if wrapped != "\x1bPtmux;\x1b\x1b]52;c;Zm9v\x07\x1b\\" {
+        Err(format!("{:?}", wrapped))?;
+    }
+    let body = "QUJD".repeat(1500);
+    let sequence = "\x1b]52;c;".to_string() + &body + "\x07";
+    let cases = [(tmux, "\x1bPtmux;", 2), (screen, "\x1bP", 79)];
+    for (multiplexer, introducer, count) in cases {
+        let wrapped = multiplexer.wrap_passthrough("\x1b]52;c;", &body, "\x07");
+        let pieces: Vec<&str> = wrapped.split_terminator("\x1b\\").collect();
+        let unwrapped: String = pieces
+            .iter()
+            .map(|piece| piece.strip_prefix(introducer).unwrap_or("\x00"))
+            .collect::<String>()
+            .replace("\x1b\x1b", "\x1b");
+        if pieces.len() != count || unwrapped != sequence {
+            Err(format!(
+                "{:?}: {} {:?}",
+                multiplexer,
+                pieces.len(),
+                pieces.first()
+            ))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_notification_sequences() -> Result<(), String> {
+    let cases = [
+        (
+            NotificationProtocol::Osc9,
+            Some("\x1b]9;Build: Done\x07".to_string()),
+        ),
+        (
+            NotificationProtocol::Osc777,
+            Some("\x1b]777;notify;Build;Done\x07".to_string()),
+        ),
+        (
+            NotificationProtocol::Kitty,
+            Some(
+                "\x1b]99;i=3:d=0:p=title;Build\x1b\\\x1b]99;i=3:d=1:p=body;Done\x1b\\".to_string(),
+            ),
+        ),
+        (NotificationProtocol::None, None),
+    ];
+    for (protocol, expected) in cases {
+        let sequence = protocol.get_sequence("Build\x07", "Done", 3);
+        if sequence != expected {
+            Err(format!("{:?}: {:?} != {:?}", protocol, sequence, expected))?;
+        }
+    }
+    let sequence = NotificationProtocol::Osc777.get_sequence("a;b", "c;d", 1);
+    if sequence.as_deref() != Some("\x1b]777;notify;ab;c;d\x07") {
+        Err(format!("Title separator not stripped: {:?}", sequence))?;
+    }
+    let kitty = from_vars(&[("KITTY_WINDOW_ID", "1")]).notifications;
+    let xterm = from_vars(&[("TERM", "xterm")]).notifications;
+    if kitty != NotificationProtocol::Kitty || xterm != NotificationProtocol::None {
+        Err(format!("{:?} {:?}", kitty, xterm))?;
+    }
+    return Ok(());
+}
@@ -33,6 +33,28 @@ pub enum ThreeBool {
     Default,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnderlineStyle {
+    Default,
+    Single,
+    Double,
+    Curly,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecLineMode {
+    Default,
+    DecSpecialGraphics,
+    Unicode,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum CursorMode {
     Default,
@@ -53,6 +75,10 @@ pub enum CursorNav {
     Backwards(u16),
     Next(u16),
     Previous(u16),
+    Column(u16),
+    Row(u16),
+    TabForwards(u16),
+    TabBackwards(u16),
 }
 
 impl CursorNav {
@@ -64,6 +90,62 @@ impl CursorNav {
             &CursorNav::Backwards(num) => "\x1b[".to_string() + &num.to_string() + "D",
             &CursorNav::Next(num) => "\x1b[".to_string() + &num.to_string() + "E",
             &CursorNav::Previous(num) => "\x1b[".to_string() + &num.to_string() + "F",
+            &CursorNav::Column(num) => "\x1b[".to_string() + &num.to_string() + "G",
+            &CursorNav::Row(num) => "\x1b[".to_string() + &num.to_string() + "d",
+            &CursorNav::TabForwards(num) => "\x1b[".to_string() + &num.to_string() + "I",
+            &CursorNav::TabBackwards(num) => "\x1b[".to_string() + &num.to_string() + "Z",
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TitleKind {
+    IconNameAndWindowTitle,
+    IconName,
+    WindowTitle,
+}
+
+impl TitleKind {
+    pub fn get_code(&self) -> &'static str {
+        return match self {
+            TitleKind::IconNameAndWindowTitle => "0",
+            TitleKind::IconName => "1",
+            TitleKind::WindowTitle => "2",
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClipboardSelection {
+    Clipboard,
+    Primary,
+}
+
+impl ClipboardSelection {
+    pub fn get_code(&self) -> &'static str {
+        return match self {
+            ClipboardSelection::Clipboard => "c",
+            ClipboardSelection::Primary => "p",
         };
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Coordinate {
+    ZeroBased(u16),
+    OneBased(u16),
+}
+
+impl Coordinate {
+    pub fn to_one_based(&self) -> Option<u16> {
+        return match *self {
+            Coordinate::ZeroBased(value) => value.checked_add(1),
+            Coordinate::OneBased(0) => None,
+            Coordinate::OneBased(value) => Some(value),
+        };
+    }
+
+    pub fn to_zero_based(&self) -> Option<u16> {
+        return self.to_one_based().map(|value| value - 1);
+    }
+}
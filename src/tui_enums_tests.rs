@@ -0,0 +1,52 @@
+use crate::tui_enums::{Coordinate, CursorNav, TitleKind};
+
+#[test]
+fn test_coordinate_conversion() -> Result<(), String> {
+    let cases = [
+        (Coordinate::ZeroBased(0), Some(1)),
+        (Coordinate::ZeroBased(9), Some(10)),
+        (Coordinate::ZeroBased(u16::MAX), None),
+        (Coordinate::OneBased(1), Some(1)),
+        (Coordinate::OneBased(0), None),
+    ];
+    for (coordinate, expected) in cases {
+        if coordinate.to_one_based() != expected {
+            Err(format!("{:?} != {:?}", coordinate, expected))?;
+        }
+    }
+    if Coordinate::OneBased(5).to_zero_based() != Some(4) {
+        Err("OneBased(5) should be ZeroBased(4)")?;
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_cursor_nav_codes() -> Result<(), String> {
+    let cases = [
+        (CursorNav::Column(12), "\x1b[12G"),
+        (CursorNav::Row(3), "\x1b[3d"),
+        (CursorNav::TabForwards(2), "\x1b[2I"),
+        (CursorNav::TabBackwards(1), "\x1b[1Z"),
+    ];
+    for (cursor_nav, expected) in cases {
+        if cursor_nav.get_code() != expected {
+            Err(format!("{:?}: {:?}", cursor_nav, cursor_nav.get_code()))?;
+        }
+    }
+    return Ok(());
+}
+
+#[test]
+fn test_title_kind_codes() -> Result<(), String> {
+    let cases = [
+        (TitleKind::IconNameAndWindowTitle, "0"),
+        (TitleKind::IconName, "1"),
+        (TitleKind::WindowTitle, "2"),
+    ];
+    for (title_kind, expected) in cases {
+        if title_kind.get_code() != expected {
+            Err(format!("{:?}: {}", title_kind, title_kind.get_code()))?;
+        }
+    }
+    return Ok(());
+}
@@ -1,5 +1,7 @@
 use std::{error::Error, fmt::Display};
 
+use crate::tui_enums::Coordinate;
+
 #[derive(Clone, Debug)]
 pub struct CError {
     pub error_string: String,
@@ -36,6 +38,15 @@ impl Display for IOError {
 }
 impl Error for IOError {}
 #[derive(Clone, Copy, Debug)]
+pub struct TuiTimeoutError {}
+
+impl Display for TuiTimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str("Timed Out Waiting For Terminal");
+    }
+}
+impl Error for TuiTimeoutError {}
+#[derive(Clone, Copy, Debug)]
 pub struct OverflowError {}
 
 impl Display for OverflowError {
@@ -60,3 +71,102 @@ impl Display for TuiUnexpectedInputError {
     }
 }
 impl Error for TuiUnexpectedInputError {}
+
+#[derive(Clone, Debug)]
+pub struct ColorParseError {
+    pub input: String,
+}
+
+impl Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(&("Invalid Color: ".to_string() + &self.input));
+    }
+}
+impl Error for ColorParseError {}
+
+#[derive(Clone, Debug)]
+pub struct ThemeParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ThemeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = "Line ".to_string() + &self.line.to_string() + ": " + &self.message;
+        return f.write_str(&result);
+    }
+}
+impl Error for ThemeParseError {}
+
+#[derive(Clone, Debug)]
+pub struct TuiUnsupportedError {
+    pub feature: String,
+}
+
+impl Display for TuiUnsupportedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(&("Unsupported By Terminal: ".to_string() + &self.feature));
+    }
+}
+impl Error for TuiUnsupportedError {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OutOfBoundsError {
+    pub coordinate: Coordinate,
+    pub limit: u16,
+}
+
+impl Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = "Coordinate Out Of Bounds: ".to_string()
+            + &format!("{:?}", self.coordinate)
+            + " (Limit "
+            + &self.limit.to_string()
+            + ")";
+        return f.write_str(&result);
+    }
+}
+impl Error for OutOfBoundsError {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ScrollRegionError {
+    pub top: u16,
+    pub bottom: u16,
+    pub height: u16,
+}
+
+impl Display for ScrollRegionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = "Invalid Scroll Region: ".to_string()
+            + &self.top.to_string()
+            + ";"
+            + &self.bottom.to_string()
+            + " (Height "
+            + &self.height.to_string()
+            + ")";
+        return f.write_str(&result);
+    }
+}
+impl Error for ScrollRegionError {}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ImageSizeError {
+    pub width: u32,
+    pub height: u32,
+    pub length: usize,
+}
+
+impl Display for ImageSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = "Expected ".to_string()
+            + &(self.width as usize * self.height as usize * 4).to_string()
+            + " Bytes For "
+            + &self.width.to_string()
+            + "x"
+            + &self.height.to_string()
+            + " RGBA Image, Got "
+            + &self.length.to_string();
+        return f.write_str(&result);
+    }
+}
+impl Error for ImageSizeError {}
@@ -8,10 +8,15 @@ pub enum TuiEvents {
     Escape,
     Backspace,
     Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
     Tab,
     Space,
     AsciiReadable(char),
     Control(char),
+    Alt(char),
     Other(char),
     LeftClick((u16, u16)),
     MiddleClick((u16, u16)),
@@ -22,6 +27,9 @@ pub enum TuiEvents {
     RightDrag((u16, u16)),
     ScrollUp((u16, u16)),
     ScrollDown((u16, u16)),
+    PasteStart,
+    PasteEnd,
+    InputClosed,
     Ignore,
     Error,
 }
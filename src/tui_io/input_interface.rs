@@ -1,4 +1,4 @@
-use std::{error::Error, fmt::Debug};
+use std::{error::Error, fmt::Debug, time::Duration};
 
 use crate::tui_events::TuiEvents;
 
@@ -9,4 +9,5 @@ pub trait InputInterfaceT: Debug {
     fn read_parsed(&self) -> TuiEvents;
     fn read_raw(&self) -> Option<char>;
     fn read_raw_immediate(&self) -> Option<char>;
+    fn read_raw_timeout(&self, timeout: Duration) -> Option<char>;
 }
@@ -1,8 +1,86 @@
+use std::{cell::RefCell, collections::VecDeque, error::Error, time::Duration};
+
 use crate::tui_events::TuiEvents;
 
 use super::input_interface::InputInterfaceT;
 
+#[derive(Debug, Default)]
+pub struct BufferedInput {
+    buffer: RefCell<VecDeque<char>>,
+    is_utf8_input: bool,
+}
+
+impl BufferedInput {
+    pub fn from_input(sequence: &str, is_utf8_input: bool) -> BufferedInput {
+        return BufferedInput {
+            buffer: RefCell::new(sequence.chars().collect()),
+            is_utf8_input,
+        };
+    }
+}
+
+impl InputInterfaceT for BufferedInput {
+    fn new() -> Result<BufferedInput, Box<dyn Error>> {
+        return Ok(BufferedInput::default());
+    }
+
+    fn read_parsed(&self) -> TuiEvents {
+        loop {
+            let Some(input_char) = self.read_raw() else {
+                return TuiEvents::InputClosed;
+            };
+            let event = self.parse_input(input_char);
+            let TuiEvents::Ignore = event else {
+                return event;
+            };
+        }
+    }
+
+    fn read_raw(&self) -> Option<char> {
+        return self.buffer.borrow_mut().pop_front();
+    }
+
+    fn read_raw_immediate(&self) -> Option<char> {
+        return self.read_raw();
+    }
+
+    fn read_raw_timeout(&self, _timeout: Duration) -> Option<char> {
+        return self.read_raw();
+    }
+}
+
+impl ParseInput for BufferedInput {
+    fn is_utf8_input(&self) -> bool {
+        return self.is_utf8_input;
+    }
+}
+
+pub fn parse_sequence(sequence: &str, is_utf8_input: bool) -> Vec<TuiEvents> {
+    let input = BufferedInput::from_input(sequence, is_utf8_input);
+    let mut events: Vec<TuiEvents> = Vec::new();
+    while let Some(input_char) = input.read_raw() {
+        match input.parse_input(input_char) {
+            TuiEvents::Ignore => {}
+            event => events.push(event),
+        }
+    }
+    return events;
+}
+
+fn get_utf8_length(lead_byte: char) -> usize {
+    return match lead_byte as u32 {
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 1,
+    };
+}
+
 pub trait ParseInput: InputInterfaceT {
+    fn is_utf8_input(&self) -> bool {
+        return false;
+    }
+
     fn parse_input(&self, input_char: char) -> TuiEvents {
         match input_char {
             '\x1b' => {
@@ -24,15 +102,35 @@ pub trait ParseInput: InputInterfaceT {
                 0x20..=0x7D => return TuiEvents::AsciiReadable(input_char),
                 0 => TuiEvents::Ignore,
                 1..=26 => return TuiEvents::Control((input_char as u8 + 0x40) as char),
+                0xC2..=0xF4 if self.is_utf8_input() => return self.handle_utf8_input(input_char),
                 _ => return TuiEvents::Other(input_char),
             },
         }
     }
+
+    fn handle_utf8_input(&self, lead_byte: char) -> TuiEvents {
+        let length = get_utf8_length(lead_byte);
+        let mut bytes: Vec<u8> = vec![lead_byte as u8];
+        for _ in 1..length {
+            match self.read_raw_immediate() {
+                Some(next) if (0x80..=0xBF).contains(&(next as u32)) => bytes.push(next as u8),
+                Some(_) | None => return TuiEvents::Error,
+            }
+        }
+        return match std::str::from_utf8(&bytes) {
+            Ok(string) => TuiEvents::Other(string.chars().next().unwrap_or(lead_byte)),
+            Err(_) => TuiEvents::Error,
+        };
+    }
     fn handle_escape_input_s1(&self) -> TuiEvents {
         let input_char_option: Option<char> = self.read_raw_immediate();
         let result = match input_char_option {
             None => TuiEvents::Escape,
             Some('[') => self.handle_escape_input_s2(),
+            Some('O') => self.handle_ss3_input(),
+            Some(input_char) if (0x20..=0x7E).contains(&(input_char as u32)) => {
+                TuiEvents::Alt(input_char)
+            }
             Some(_) => TuiEvents::Error,
         };
         match result {
@@ -52,16 +150,48 @@ pub trait ParseInput: InputInterfaceT {
             Some('B') => TuiEvents::DownArrow,
             Some('C') => TuiEvents::RightArrow,
             Some('D') => TuiEvents::LeftArrow,
-            Some('3') => {
-                if let Some('~') = self.read_raw_immediate() {
-                    return TuiEvents::Delete;
+            Some('H') => TuiEvents::Home,
+            Some('F') => TuiEvents::End,
+            Some(input_char @ '0'..='9') => self.handle_tilde_input(input_char),
+            Some('<') => self.handle_mouse_events(),
+            Some(_) | None => TuiEvents::Error,
+        };
+    }
+    fn handle_tilde_input(&self, first_digit: char) -> TuiEvents {
+        let mut number: u32 = first_digit as u32 - 0x30;
+        loop {
+            match self.read_raw_immediate() {
+                Some(digit @ '0'..='9') if number < 1000 => {
+                    number = number * 10 + (digit as u32 - 0x30)
                 }
-                TuiEvents::Error
+                Some('~') => break,
+                Some(_) | None => return TuiEvents::Error,
             }
-            Some('<') => self.handle_mouse_events(),
+        }
+        return match number {
+            1 | 7 => TuiEvents::Home,
+            3 => TuiEvents::Delete,
+            4 | 8 => TuiEvents::End,
+            5 => TuiEvents::PageUp,
+            6 => TuiEvents::PageDown,
+            200 => TuiEvents::PasteStart,
+            201 => TuiEvents::PasteEnd,
+            _ => TuiEvents::Ignore,
+        };
+    }
+
+    fn handle_ss3_input(&self) -> TuiEvents {
+        return match self.read_raw_immediate() {
+            Some('A') => TuiEvents::UpArrow,
+            Some('B') => TuiEvents::DownArrow,
+            Some('C') => TuiEvents::RightArrow,
+            Some('D') => TuiEvents::LeftArrow,
+            Some('H') => TuiEvents::Home,
+            Some('F') => TuiEvents::End,
             Some(_) | None => TuiEvents::Error,
         };
     }
+
     fn handle_mouse_events(&self) -> TuiEvents {
         return match self.read_raw_immediate() {
             Some('0') => match self.get_coordinates() {
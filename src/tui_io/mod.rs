@@ -6,4 +6,6 @@ pub mod terminal_interface;
 #[cfg_attr(unix, path = "unix_tui_io.rs")]
 pub mod tui_io;
 
-mod input_parser;
+pub mod input_parser;
+
+pub mod reply_parser;
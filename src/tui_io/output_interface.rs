@@ -1,7 +1,10 @@
 use std::fmt::Debug;
 
-use crate::tui_errors::CError;
+use crate::{tui_enums::TitleKind, tui_errors::CError};
 
 pub trait OutputInterfaceT: Debug {
     fn get_size(&self) -> Result<(u16, u16), CError>;
+    fn set_title(&mut self, title: &str, title_kind: TitleKind);
+    fn push_title(&mut self);
+    fn pop_title(&mut self);
 }
@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use crate::{tui_enums::Color, tui_errors::TuiTimeoutError};
+
+use super::input_interface::InputInterfaceT;
+
+fn parse_color_component(component: &str) -> Option<u8> {
+    if component.is_empty() || component.len() > 4 {
+        return None;
+    }
+    let value = u32::from_str_radix(component, 16).ok()?;
+    let max = (1u32 << (4 * component.len())) - 1;
+    return Some(((value * 255 + max / 2) / max) as u8);
+}
+
+pub fn parse_color_reply(reply: &str) -> Option<Color> {
+    let components = reply
+        .strip_prefix("rgb:")
+        .or_else(|| reply.strip_prefix("rgba:"))?;
+    let mut components = components.split('/');
+    let r = parse_color_component(components.next()?)?;
+    let g = parse_color_component(components.next()?)?;
+    let b = parse_color_component(components.next()?)?;
+    return Some(Color::RGB(r, g, b));
+}
+
+pub fn parse_cursor_position_reply(reply: &str) -> Option<(u16, u16)> {
+    let (y, x) = reply
+        .strip_prefix('[')?
+        .strip_suffix('R')?
+        .split_once(';')?;
+    return Some((x.parse().ok()?, y.parse().ok()?));
+}
+
+pub fn parse_cell_size_reply(reply: &str) -> Option<(u16, u16)> {
+    let (height, width) = reply
+        .strip_prefix("[6;")?
+        .strip_suffix('t')?
+        .split_once(';')?;
+    let (width, height): (u16, u16) = (width.parse().ok()?, height.parse().ok()?);
+    if width == 0 || height == 0 {
+        return None;
+    }
+    return Some((width, height));
+}
+
+fn read_raw_before<I: InputInterfaceT>(
+    input: &I,
+    deadline: Instant,
+) -> Result<char, TuiTimeoutError> {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    return input.read_raw_timeout(remaining).ok_or(TuiTimeoutError {});
+}
+
+fn read_string_sequence<I: InputInterfaceT>(
+    input: &I,
+    sequence: &mut String,
+    deadline: Instant,
+) -> Result<(), TuiTimeoutError> {
+    loop {
+        match read_raw_before(input, deadline)? {
+            '\x07' => return Ok(()),
+            '\x1b' => {
+                read_raw_before(input, deadline)?;
+                return Ok(());
+            }
+            input_char => sequence.push(input_char),
+        }
+    }
+}
+
+pub(crate) fn read_reply_sequence<I: InputInterfaceT, F: Fn(&str) -> bool>(
+    input: &I,
+    is_reply: F,
+    deadline: Instant,
+    user_input: &mut String,
+) -> Result<String, TuiTimeoutError> {
+    loop {
+        let input_char = read_raw_before(input, deadline)?;
+        if input_char != '\x1b' {
+            user_input.push(input_char);
+            continue;
+        }
+        let Some(introducer) = input.read_raw_immediate() else {
+            user_input.push('\x1b');
+            continue;
+        };
+        let mut sequence: String = introducer.to_string();
+        match introducer {
+            '[' => loop {
+                let input_char = read_raw_before(input, deadline)?;
+                sequence.push(input_char);
+                if ('\x40'..='\x7e').contains(&input_char) {
+                    break;
+                }
+            },
+            ']' | 'P' | '_' => {
+                let Some(input_char) = input.read_raw_immediate() else {
+                    user_input.push('\x1b');
+                    user_input.push(introducer);
+                    continue;
+                };
+                sequence.push(input_char);
+                read_string_sequence(input, &mut sequence, deadline)?;
+                if is_reply(&sequence) {
+                    return Ok(sequence);
+                }
+                continue;
+            }
+            'O' => {
+                if let Some(input_char) = input.read_raw_immediate() {
+                    sequence.push(input_char);
+                }
+            }
+            _ => {}
+        }
+        if is_reply(&sequence) {
+            return Ok(sequence);
+        }
+        user_input.push('\x1b');
+        user_input.push_str(&sequence);
+    }
+}
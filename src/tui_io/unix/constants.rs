@@ -15,6 +15,7 @@ pub const OPOST: u64 = 0x1;
 #[cfg(target_os = "macos")]
 pub const O_NONBLOCK: i32 = 4;
 
+pub const POLLIN: i16 = 0x1;
 pub const STDOUT_FILENO: i32 = 1;
 pub const TCSADRAIN: i32 = 1;
 #[cfg(not(target_os = "macos"))]
@@ -2,7 +2,7 @@ use std::ffi::{c_void, CStr};
 
 use crate::tui_errors::CError;
 
-use super::structs::Termios;
+use super::structs::{NfdsT, PollFd, Termios};
 extern "C" {
     pub fn cfmakeraw(termios: *mut Termios);
     pub fn fcntl(fd: i32, cmd: i32, ...) -> i32;
@@ -10,6 +10,7 @@ extern "C" {
     pub fn tcgetattr(fd: i32, termios: *mut Termios) -> i32;
     pub fn tcsetattr(fd: i32, optional_actions: i32, termios: *const Termios) -> i32;
     pub fn read(fd: i32, buf: *mut c_void, count: usize) -> isize;
+    pub fn poll(fds: *mut PollFd, nfds: NfdsT, timeout: i32) -> i32;
     fn strerror(errno: u32) -> *const i8;
     fn __error() -> *mut u32;
     fn __errno_location() -> *mut u32;
@@ -44,3 +44,17 @@ pub struct Winsize {
     pub ws_xpixel: u16,
     pub ws_ypixel: u16,
 }
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct PollFd {
+    pub fd: i32,
+    pub events: i16,
+    pub revents: i16,
+}
+
+#[cfg(not(target_os = "macos"))]
+pub type NfdsT = u64;
+
+#[cfg(target_os = "macos")]
+pub type NfdsT = u32;
@@ -1,3 +1,4 @@
+use crate::tui_enums::TitleKind;
 use crate::tui_errors::CError;
 use crate::tui_events::TuiEvents;
 use std::error::Error;
@@ -9,10 +10,13 @@ use unix::functions::{cfmakeraw, read as c_read};
 
 use std::io::{stdin, stdout, Stdout, Write};
 use std::os::unix::prelude::AsRawFd;
+use std::time::Duration;
 
-use self::unix::constants::{ONLCR, OPOST, O_NONBLOCK, STDOUT_FILENO, TCSADRAIN, TIOCGWINSZ};
-use self::unix::functions::{fcntl, get_errno_error, ioctl, tcgetattr, tcsetattr};
-use self::unix::structs::{Termios, Winsize};
+use self::unix::constants::{
+    ONLCR, OPOST, O_NONBLOCK, POLLIN, STDOUT_FILENO, TCSADRAIN, TIOCGWINSZ,
+};
+use self::unix::functions::{fcntl, get_errno_error, ioctl, poll, tcgetattr, tcsetattr};
+use self::unix::structs::{PollFd, Termios, Winsize};
 
 use super::input_interface::InputInterfaceT;
 use super::input_parser::ParseInput;
@@ -63,13 +67,20 @@ impl InputInterface {
         return termios_struct;
     }
 
-    fn read_char(&self) -> char {
+    fn read_char(&self) -> Option<char> {
         let mut buffer: [c_char; 1] = [0];
-        unsafe {
-            c_read(self.input_fd.clone(), buffer.as_mut_ptr() as *mut c_void, 1);
-        };
-
-        return (buffer[0] as u8) as char;
+        loop {
+            let read_count =
+                unsafe { c_read(self.input_fd.clone(), buffer.as_mut_ptr() as *mut c_void, 1) };
+            match read_count {
+                1 => return Some((buffer[0] as u8) as char),
+                0 => return None,
+                _ if std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted => {
+                    continue
+                }
+                _ => return None,
+            }
+        }
     }
 }
 
@@ -82,7 +93,7 @@ impl InputInterfaceT for InputInterface {
     fn read_parsed(&self) -> TuiEvents {
         loop {
             let Some(input_char) = self.read_raw() else {
-                return TuiEvents::Error;
+                return TuiEvents::InputClosed;
             };
             let event = self.parse_input(input_char);
             let TuiEvents::Ignore = event else {
@@ -92,16 +103,35 @@ impl InputInterfaceT for InputInterface {
     }
 
     fn read_raw(&self) -> Option<char> {
-        return Some(self.read_char());
+        return self.read_char();
     }
 
     fn read_raw_immediate(&self) -> Option<char> {
         let input_interface = NonBlockInputInterface::new(self.input_fd);
         return input_interface.read_raw_immediate();
     }
+
+    fn read_raw_timeout(&self, timeout: Duration) -> Option<char> {
+        let mut poll_fd: PollFd = PollFd {
+            fd: self.input_fd,
+            events: POLLIN,
+            revents: 0,
+        };
+        let milliseconds: i32 = timeout.as_millis().min(i32::MAX as u128) as i32;
+        unsafe {
+            if 1 != poll(&mut poll_fd, 1, milliseconds) {
+                return None;
+            }
+        }
+        return self.read_raw_immediate();
+    }
 }
 
-impl ParseInput for InputInterface {}
+impl ParseInput for InputInterface {
+    fn is_utf8_input(&self) -> bool {
+        return true;
+    }
+}
 
 struct NonBlockInputInterface {
     input_fd: i32,
@@ -148,6 +178,24 @@ impl OutputInterfaceT for OutputInterface {
         }
         return Ok((window_size.ws_col as u16, window_size.ws_row as u16));
     }
+
+    fn set_title(&mut self, title: &str, title_kind: TitleKind) {
+        let title: String = title.chars().filter(|c| !c.is_control()).collect();
+        _ = self.write(
+            ("\x1b]".to_string() + title_kind.get_code() + ";" + &title + "\x1b\\").as_bytes(),
+        );
+        _ = self.flush();
+    }
+
+    fn push_title(&mut self) {
+        _ = self.write(b"\x1b[22;0t");
+        _ = self.flush();
+    }
+
+    fn pop_title(&mut self) {
+        _ = self.write(b"\x1b[23;0t");
+        _ = self.flush();
+    }
 }
 
 impl Write for OutputInterface {
@@ -3,3 +3,4 @@ pub const STD_OUTPUT_HANDLE: i32 = (u32::MAX - 10) as i32;
 pub const ENABLE_EXTENDED_FLAGS: u32 = 0x80;
 pub const ENABLE_VIRTUAL_TERMINAL_INPUT: u32 = 0x200;
 pub const KEY_EVENT: u32 = 0x1;
+pub const WAIT_OBJECT_0: u32 = 0x0;
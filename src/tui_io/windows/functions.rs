@@ -17,6 +17,9 @@ extern "C" {
         lpConsoleScreenBufferInfo: *mut CONSOLE_SCREEN_BUFFER_INFO,
     ) -> BOOL;
     pub fn GetNumberOfConsoleInputEvents(hConsoleInput: HANDLE, numberOfEvents: *mut u32) -> BOOL;
+    pub fn WaitForSingleObject(hHandle: HANDLE, dwMilliseconds: u32) -> u32;
+    pub fn SetConsoleTitleW(lpConsoleTitle: *const u16) -> BOOL;
+    pub fn GetConsoleTitleW(lpConsoleTitle: *mut u16, nSize: u32) -> u32;
     fn GetLastError() -> u32;
     fn FormatMessageA(
         dwFlages: u32,
@@ -1,11 +1,13 @@
 use std::{
     error::Error,
     io::{stdout, Stdout, Write},
+    time::{Duration, Instant},
 };
 
 mod windows;
 
 use crate::{
+    tui_enums::TitleKind,
     tui_errors::CError,
     tui_events::TuiEvents,
     tui_io::{
@@ -14,13 +16,14 @@ use crate::{
     },
 };
 
-use windows::constants::{KEY_EVENT, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE};
+use windows::constants::{KEY_EVENT, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE, WAIT_OBJECT_0};
 
 use windows::{
     constants::{ENABLE_EXTENDED_FLAGS, ENABLE_VIRTUAL_TERMINAL_INPUT},
     functions::{
-        get_std_handle, GetConsoleMode, GetConsoleScreenBufferInfo, GetNumberOfConsoleInputEvents,
-        ReadConsoleInputW, SetConsoleMode,
+        get_std_handle, GetConsoleMode, GetConsoleScreenBufferInfo, GetConsoleTitleW,
+        GetNumberOfConsoleInputEvents, ReadConsoleInputW, SetConsoleMode, SetConsoleTitleW,
+        WaitForSingleObject,
     },
     structs::{CONSOLE_MODE, CONSOLE_SCREEN_BUFFER_INFO, COORD, HANDLE, KEY_EVENT_RECORD},
 };
@@ -35,6 +38,7 @@ pub struct InputInterface {
 #[derive(Debug)]
 pub struct OutputInterface {
     output_handle: Stdout,
+    title_stack: Vec<Vec<u16>>,
 }
 pub struct TerminalManager {}
 #[derive(Clone, Copy, Debug)]
@@ -72,6 +76,26 @@ impl InputInterface {
         return Some(count > 0);
     }
 
+    fn read_unit_immediate(&self) -> Option<u16> {
+        loop {
+            if !self.is_event_ready()? {
+                return None;
+            }
+            let event = self.get_event().ok()?;
+            if let Some(unit) = get_key_unit(&event) {
+                return Some(unit);
+            }
+        }
+    }
+
+    fn decode_unit(&self, unit: u16) -> Option<char> {
+        if !(0xD800..=0xDBFF).contains(&unit) {
+            return char::from_u32(unit as u32);
+        }
+        let low_unit = self.read_unit_immediate()?;
+        return char::decode_utf16([unit, low_unit]).next()?.ok();
+    }
+
     fn get_event(&self) -> Result<INPUT_RECORD, String> {
         let event = &mut INPUT_RECORD::default();
         let mut event_count = 0;
@@ -84,6 +108,18 @@ impl InputInterface {
     }
 }
 
+fn get_key_unit(event: &INPUT_RECORD) -> Option<u16> {
+    if event.event_type as u32 != KEY_EVENT {
+        return None;
+    }
+    let key_event_data: KEY_EVENT_RECORD;
+    unsafe { key_event_data = event.event.key_event }
+    if !key_event_data.key_down.as_bool() {
+        return None;
+    }
+    return Some(unsafe { key_event_data.u_char.unicode_char });
+}
+
 impl ParseInput for InputInterface {}
 
 impl InputInterfaceT for InputInterface {
@@ -99,24 +135,18 @@ impl InputInterfaceT for InputInterface {
     fn read_parsed(&self) -> TuiEvents {
         loop {
             let Ok(event) = self.get_event() else {
+                return TuiEvents::InputClosed;
+            };
+            let Some(unit) = get_key_unit(&event) else {
+                continue;
+            };
+            let Some(input_char) = self.decode_unit(unit) else {
                 return TuiEvents::Error;
             };
-            match event.event_type as u32 {
-                KEY_EVENT => {
-                    let key_event_data: KEY_EVENT_RECORD;
-                    unsafe { key_event_data = event.event.key_event }
-                    if key_event_data.key_down.as_bool() {
-                        let input_char = unsafe { key_event_data.u_char.ascii_char as char };
-                        let event: TuiEvents = self.parse_input(input_char);
-                        match event {
-                            TuiEvents::Ignore => continue,
-                            _ => return event,
-                        }
-                    } else {
-                        continue;
-                    }
-                }
-                _ => continue,
+            let event: TuiEvents = self.parse_input(input_char);
+            match event {
+                TuiEvents::Ignore => continue,
+                _ => return event,
             }
         }
     }
@@ -124,46 +154,32 @@ impl InputInterfaceT for InputInterface {
     fn read_raw(&self) -> Option<char> {
         loop {
             let event = self.get_event().ok()?;
-            match event.event_type as u32 {
-                KEY_EVENT => {
-                    let key_event_data: KEY_EVENT_RECORD;
-                    unsafe { key_event_data = event.event.key_event }
-                    if key_event_data.key_down.as_bool() {
-                        let result: char;
-                        unsafe {
-                            result = key_event_data.u_char.ascii_char.try_into().ok()?;
-                        }
-                        return Some(result);
-                    } else {
-                        continue;
-                    }
-                }
-                _ => continue,
+            if let Some(unit) = get_key_unit(&event) {
+                return self.decode_unit(unit);
             }
         }
     }
 
     fn read_raw_immediate(&self) -> Option<char> {
+        let unit = self.read_unit_immediate()?;
+        return self.decode_unit(unit);
+    }
+
+    fn read_raw_timeout(&self, timeout: Duration) -> Option<char> {
+        let deadline = Instant::now() + timeout;
         loop {
-            if !self.is_event_ready()? {
-                return None;
-            }
-            let event = self.get_event().ok()?;
-            match event.event_type as u32 {
-                KEY_EVENT => {
-                    let key_event_data: KEY_EVENT_RECORD;
-                    unsafe { key_event_data = event.event.key_event }
-                    if key_event_data.key_down.as_bool() {
-                        let result: char;
-                        unsafe {
-                            result = key_event_data.u_char.ascii_char.try_into().ok()?;
-                        }
-                        return Some(result);
-                    } else {
-                        continue;
-                    }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let milliseconds: u32 = remaining.as_millis().min(u32::MAX as u128) as u32;
+            unsafe {
+                if WaitForSingleObject(self.input_handle, milliseconds) != WAIT_OBJECT_0 {
+                    return None;
                 }
-                _ => continue,
+            }
+            if let Some(result) = self.read_raw_immediate() {
+                return Some(result);
+            }
+            if remaining.is_zero() {
+                return None;
             }
         }
     }
@@ -183,6 +199,38 @@ impl OutputInterfaceT for OutputInterface {
         let size: COORD = screen_info_struct.size;
         return Ok((size.x as u16, size.y as u16));
     }
+
+    fn set_title(&mut self, title: &str, title_kind: TitleKind) {
+        if let TitleKind::IconName = title_kind {
+            return;
+        }
+        let title: Vec<u16> = title
+            .chars()
+            .filter(|c| !c.is_control())
+            .collect::<String>()
+            .encode_utf16()
+            .chain([0])
+            .collect();
+        unsafe {
+            SetConsoleTitleW(title.as_ptr());
+        }
+    }
+
+    fn push_title(&mut self) {
+        let mut title: Vec<u16> = vec![0; 1024];
+        let length = unsafe { GetConsoleTitleW(title.as_mut_ptr(), title.len() as u32) };
+        title.truncate(length as usize);
+        title.push(0);
+        self.title_stack.push(title);
+    }
+
+    fn pop_title(&mut self) {
+        if let Some(title) = self.title_stack.pop() {
+            unsafe {
+                SetConsoleTitleW(title.as_ptr());
+            }
+        }
+    }
 }
 
 impl Write for OutputInterface {
@@ -202,6 +250,7 @@ impl TerminalTrait for TerminalManager {
         let console_mode: CONSOLE_MODE = input_interface.get_console_mode()?;
         let output_interface: OutputInterface = OutputInterface {
             output_handle: stdout(),
+            title_stack: Vec::new(),
         };
         let new_mode: CONSOLE_MODE =
             CONSOLE_MODE(ENABLE_EXTENDED_FLAGS | ENABLE_VIRTUAL_TERMINAL_INPUT);
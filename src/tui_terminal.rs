@@ -1,29 +1,76 @@
 use std::{
+    cell::RefCell,
+    collections::VecDeque,
     error::Error,
     io::Write,
     sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
 };
 
 static TUI_TERMINAL_LOCK: Mutex<()> = Mutex::new(());
+const QUERY_TIMEOUT: Duration = Duration::from_millis(500);
+const DEFAULT_CELL_PIXEL_SIZE: (u16, u16) = (10, 20);
 
 use crate::{
+    base64,
+    box_drawing::{BorderStyle, LineCells, LINE_DOWN, LINE_LEFT, LINE_RIGHT, LINE_UP},
+    canvas::Canvas,
     font_settings::FontSettings,
-    tui_enums::{CursorMode, CursorNav, TuiMode},
-    tui_errors::{CError, IOError, OverflowError, TuiUnexpectedInputError},
+    image::{encode_iterm2, encode_kitty, encode_sixel, Image},
+    rect::Rect,
+    region::Region,
+    string_plus::{dec_to_unicode, unicode_to_dec, Hyperlink, HYPERLINK_CLOSE_CODE},
+    theme::Theme,
+    tui_capabilities::{Capabilities, ImageProtocol, TerminalKind},
+    tui_enums::{
+        ClipboardSelection, Coordinate, CursorMode, CursorNav, DecLineMode, TitleKind, TuiMode,
+        UnderlineStyle,
+    },
+    tui_errors::{CError, OutOfBoundsError, ScrollRegionError, TuiUnsupportedError},
     tui_events::TuiEvents,
     tui_io::{
         input_interface::InputInterfaceT,
+        input_parser::{parse_sequence, ParseInput},
         output_interface::OutputInterfaceT,
+        reply_parser::{
+            parse_cell_size_reply, parse_color_reply, parse_cursor_position_reply,
+            read_reply_sequence,
+        },
         terminal_interface::TerminalTrait,
         tui_io::{InputInterface, OutputInterface, TerminalManager, TerminalState},
     },
+    widget::Widget,
     Color, StringPlus, ThreeBool,
 };
 
+type PlacedImage = (Rect, u32);
+
+#[derive(Clone, Debug, Default)]
+struct ColorOverrides {
+    palette: Vec<u8>,
+    foreground: bool,
+    background: bool,
+    cursor: bool,
+}
+
 #[derive(Debug)]
 pub struct TuiTerminal {
     font_settings: FontSettings,
     cursor_mode: CursorMode,
+    capabilities: Capabilities,
+    color_overrides: ColorOverrides,
+    theme: Theme,
+    line_cells: LineCells,
+    cursor_cell: Option<(u16, u16)>,
+    pending_events: RefCell<VecDeque<TuiEvents>>,
+    scroll_region: Option<(u16, u16)>,
+    horizontal_margins: Option<(u16, u16)>,
+    title_stack_depth: usize,
+    notification_count: u32,
+    images: Vec<PlacedImage>,
+    image_count: u32,
+    cell_pixel_size: Option<(u16, u16)>,
+    bracketed_paste: bool,
     output_interface: OutputInterface,
     input_interface: InputInterface,
     terminal_state: TerminalState,
@@ -41,6 +88,20 @@ impl TuiTerminal {
         let mut tui_terminal = TuiTerminal {
             font_settings: FontSettings::default(),
             cursor_mode: CursorMode::Default,
+            capabilities: Capabilities::detect(),
+            color_overrides: ColorOverrides::default(),
+            theme: Theme::default(),
+            line_cells: LineCells::default(),
+            cursor_cell: None,
+            pending_events: RefCell::new(VecDeque::new()),
+            scroll_region: None,
+            horizontal_margins: None,
+            title_stack_depth: 0,
+            notification_count: 0,
+            images: Vec::new(),
+            image_count: 0,
+            cell_pixel_size: None,
+            bracketed_paste: false,
             output_interface: output_interface,
             input_interface: input_interface,
             terminal_state: terminal_state,
@@ -54,212 +115,203 @@ impl TuiTerminal {
         return Ok(tui_terminal);
     }
 
-    fn get_font_color_code(&self, mut color: Color) -> String {
-        if let Color::Default = color {
-            color = self.font_settings.font_color;
-        }
-        let ascii_code: String;
-        return match color {
-            Color::White => "37",
-            Color::BrightWhite => "97",
-            Color::Black => "30",
-            Color::BrightBlack => "90",
-            Color::Red => "31",
-            Color::BrightRed => "91",
-            Color::Green => "32",
-            Color::BrightGreen => "92",
-            Color::Blue => "34",
-            Color::BrightBlue => "94",
-            Color::Yellow => "33",
-            Color::BrightYellow => "93",
-            Color::Magenta => "35",
-            Color::BrightMagenta => "95",
-            Color::Cyan => "36",
-            Color::BrightCyan => "96",
-            Color::CC256(code) => {
-                ascii_code = "38;5;".to_string() + &code.to_string();
-                ascii_code.as_str()
-            }
-            Color::RGB(r, g, b) => {
-                ascii_code = "38;2;".to_string()
-                    + &r.to_string()
-                    + ";"
-                    + &g.to_string()
-                    + ";"
-                    + &b.to_string();
-                ascii_code.as_str()
-            }
-            Color::Default => "39",
-        }
-        .into();
-    }
-
-    fn get_background_color_code(&self, mut color: Color) -> String {
-        if let Color::Default = color {
-            color = self.font_settings.background_color;
-        }
-        let ascii_code: String;
-        return match color {
-            Color::White => "47",
-            Color::BrightWhite => "107",
-            Color::Black => "40",
-            Color::BrightBlack => "100",
-            Color::Red => "41",
-            Color::BrightRed => "101",
-            Color::Green => "42",
-            Color::BrightGreen => "102",
-            Color::Blue => "44",
-            Color::BrightBlue => "104",
-            Color::Yellow => "43",
-            Color::BrightYellow => "103",
-            Color::Magenta => "45",
-            Color::BrightMagenta => "105",
-            Color::Cyan => "46",
-            Color::BrightCyan => "106",
-            Color::CC256(code) => {
-                ascii_code = "48;5;".to_string() + &code.to_string();
-                ascii_code.as_str()
-            }
-            Color::RGB(r, g, b) => {
-                ascii_code = "48;2;".to_string()
-                    + &r.to_string()
-                    + ";"
-                    + &g.to_string()
-                    + ";"
-                    + &b.to_string();
-                ascii_code.as_str()
-            }
-            Color::Default => "49",
-        }
-        .into();
+    pub fn shift_cursor(&mut self, cursor_nav: CursorNav) {
+        self.cursor_cell = None;
+        _ = self
+            .output_interface
+            .write(cursor_nav.get_code().as_bytes());
+        _ = self.output_interface.flush();
     }
 
-    fn get_bold_code(&self, mut is_bold: ThreeBool) -> &str {
-        if let ThreeBool::Default = is_bold {
-            is_bold = self.font_settings.is_bold;
-        }
-        return match is_bold {
-            ThreeBool::True => "1",
-            ThreeBool::False | ThreeBool::Default => "22",
-        };
+    pub fn set_cursor_position(&mut self, x: u16, y: u16) {
+        self.cursor_cell = Some((x, y));
+        _ = self
+            .output_interface
+            .write(("\x1b[".to_string() + &y.to_string() + ";" + &x.to_string() + "H").as_bytes());
+        _ = self.output_interface.flush();
     }
 
-    fn get_underlined_code(&self, mut is_underlined: ThreeBool) -> &str {
-        if let ThreeBool::Default = is_underlined {
-            is_underlined = self.font_settings.is_underlined;
-        }
-        return match is_underlined {
-            ThreeBool::True => "4",
-            ThreeBool::False | ThreeBool::Default => "24",
+    fn validate_coordinate(coordinate: Coordinate, limit: u16) -> Result<u16, OutOfBoundsError> {
+        return match coordinate.to_one_based() {
+            Some(value) if value <= limit => Ok(value),
+            _ => Err(OutOfBoundsError { coordinate, limit }),
         };
     }
 
-    fn get_italics_code(&self, mut is_italics: ThreeBool) -> &str {
-        if let ThreeBool::Default = is_italics {
-            is_italics = self.font_settings.is_underlined;
-        }
-        return match is_italics {
-            ThreeBool::True => "3",
-            ThreeBool::False | ThreeBool::Default => "23",
-        };
+    pub fn move_cursor_to(&mut self, x: Coordinate, y: Coordinate) -> Result<(), Box<dyn Error>> {
+        let (width, height) = self.get_teminal_size()?;
+        let x = TuiTerminal::validate_coordinate(x, width)?;
+        let y = TuiTerminal::validate_coordinate(y, height)?;
+        self.set_cursor_position(x, y);
+        return Ok(());
     }
 
-    fn get_inverted_code(&self, mut is_inverted: ThreeBool) -> &str {
-        if let ThreeBool::Default = is_inverted {
-            is_inverted = self.font_settings.is_inverted;
-        }
-        return match is_inverted {
-            ThreeBool::True => "7",
-            ThreeBool::False | ThreeBool::Default => "27",
-        };
+    pub fn set_cursor_column(&mut self, x: Coordinate) -> Result<(), Box<dyn Error>> {
+        let (width, _) = self.get_teminal_size()?;
+        let x = TuiTerminal::validate_coordinate(x, width)?;
+        self.shift_cursor(CursorNav::Column(x));
+        return Ok(());
     }
 
-    fn get_blinking_code(&self, mut is_blinking: ThreeBool) -> &str {
-        if let ThreeBool::Default = is_blinking {
-            is_blinking = self.font_settings.is_blinking;
-        }
-        return match is_blinking {
-            ThreeBool::True => "5",
-            ThreeBool::False | ThreeBool::Default => "25",
-        };
+    pub fn set_cursor_row(&mut self, y: Coordinate) -> Result<(), Box<dyn Error>> {
+        let (_, height) = self.get_teminal_size()?;
+        let y = TuiTerminal::validate_coordinate(y, height)?;
+        self.shift_cursor(CursorNav::Row(y));
+        return Ok(());
     }
 
-    pub fn shift_cursor(&mut self, cursor_nav: CursorNav) {
+    pub fn set_tab_stop(&mut self) {
+        _ = self.output_interface.write(b"\x1bH");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn clear_tab_stop(&mut self) {
+        _ = self.output_interface.write(b"\x1b[0g");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn clear_all_tab_stops(&mut self) {
+        _ = self.output_interface.write(b"\x1b[3g");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn get_cursor_position(&mut self) -> Result<(u16, u16), Box<dyn Error>> {
+        _ = self.output_interface.write(b"\x1b[6n");
+        _ = self.output_interface.flush();
+        let reply = self.read_reply(|reply| reply.starts_with('[') && reply.ends_with('R'))?;
+        return Ok(parse_cursor_position_reply(&reply).ok_or(CError::from(
+            "Invalid Cursor Position Reply: ".to_string() + &reply,
+        ))?);
+    }
+
+    fn queue_user_input(&self, sequence: &str) {
+        self.pending_events.borrow_mut().extend(parse_sequence(
+            sequence,
+            self.input_interface.is_utf8_input(),
+        ));
+    }
+
+    fn read_reply<F: Fn(&str) -> bool>(&self, is_reply: F) -> Result<String, Box<dyn Error>> {
+        let mut user_input = String::new();
+        let reply = read_reply_sequence(
+            &self.input_interface,
+            is_reply,
+            Instant::now() + QUERY_TIMEOUT,
+            &mut user_input,
+        );
+        self.queue_user_input(&user_input);
+        return Ok(reply?);
+    }
+
+    fn read_osc_reply(&mut self, prefix: &str) -> Result<String, Box<dyn Error>> {
+        let osc_prefix: String = "]".to_string() + prefix;
+        let reply = self.read_reply(|reply| reply.starts_with(&osc_prefix))?;
+        return Ok(reply[osc_prefix.len()..].to_string());
+    }
+
+    fn query_color(&mut self, code: &str) -> Result<Color, Box<dyn Error>> {
+        let prefix: String = code.to_string() + ";";
         _ = self
             .output_interface
-            .write(cursor_nav.get_code().as_bytes());
+            .write(("\x1b]".to_string() + &prefix + "?\x1b\\").as_bytes());
         _ = self.output_interface.flush();
+        let reply = self.read_osc_reply(&prefix)?;
+        return Ok(parse_color_reply(&reply)
+            .ok_or(CError::from("Invalid Color Reply: ".to_string() + &reply))?);
     }
 
-    pub fn set_cursor_position(&mut self, x: u16, y: u16) {
+    pub fn query_foreground_color(&mut self) -> Result<Color, Box<dyn Error>> {
+        return self.query_color("10");
+    }
+
+    pub fn query_background_color(&mut self) -> Result<Color, Box<dyn Error>> {
+        return self.query_color("11");
+    }
+
+    pub fn query_palette_color(&mut self, index: u8) -> Result<Color, Box<dyn Error>> {
+        return self.query_color(&("4;".to_string() + &index.to_string()));
+    }
+
+    pub fn is_dark_background(&mut self) -> Result<bool, Box<dyn Error>> {
+        let background = self.query_background_color()?;
+        let white_contrast = background
+            .contrast_ratio(Color::RGB(255, 255, 255))
+            .unwrap_or(0.0);
+        let black_contrast = background
+            .contrast_ratio(Color::RGB(0, 0, 0))
+            .unwrap_or(0.0);
+        return Ok(white_contrast > black_contrast);
+    }
+
+    fn get_color_spec(color: Color) -> Option<String> {
+        let (r, g, b) = color.to_rgb()?;
+        return Some(format!("rgb:{:02x}/{:02x}/{:02x}", r, g, b));
+    }
+
+    fn send_color_override(&mut self, code: &str, reset_code: &str, color: Color) -> bool {
+        let sequence: String = match TuiTerminal::get_color_spec(color) {
+            Some(spec) => "\x1b]".to_string() + code + ";" + &spec + "\x1b\\",
+            None => "\x1b]".to_string() + reset_code + "\x1b\\",
+        };
+        _ = self.output_interface.write(sequence.as_bytes());
+        _ = self.output_interface.flush();
+        return color.to_rgb().is_some();
+    }
+
+    pub fn set_palette_color(&mut self, index: u8, color: Color) {
+        let code: String = "4;".to_string() + &index.to_string();
+        let reset_code: String = "104;".to_string() + &index.to_string();
+        let is_set = self.send_color_override(&code, &reset_code, color);
+        self.color_overrides
+            .palette
+            .retain(|&palette_index| palette_index != index);
+        if is_set {
+            self.color_overrides.palette.push(index);
+        }
+    }
+
+    pub fn set_default_foreground_color(&mut self, color: Color) {
+        self.color_overrides.foreground = self.send_color_override("10", "110", color);
+    }
+
+    pub fn set_default_background_color(&mut self, color: Color) {
+        self.color_overrides.background = self.send_color_override("11", "111", color);
+    }
+
+    pub fn set_cursor_color(&mut self, color: Color) {
+        self.color_overrides.cursor = self.send_color_override("12", "112", color);
+    }
+
+    pub fn reset_palette(&mut self) {
+        _ = self.output_interface.write(b"\x1b]104\x1b\\");
+        _ = self.output_interface.flush();
+        self.color_overrides.palette.clear();
+    }
+
+    pub fn reset_default_colors(&mut self) {
         _ = self
             .output_interface
-            .write(("\x1b[".to_string() + &y.to_string() + ";" + &x.to_string() + "H").as_bytes());
+            .write(b"\x1b]110\x1b\\\x1b]111\x1b\\\x1b]112\x1b\\");
         _ = self.output_interface.flush();
+        self.color_overrides.foreground = false;
+        self.color_overrides.background = false;
+        self.color_overrides.cursor = false;
     }
 
-    pub fn get_cursor_position(&mut self) -> Result<(u16, u16), Box<dyn Error>> {
-        _ = self.output_interface.write(b"\x1b[6n");
-        _ = self.output_interface.flush();
-        let mut input = self.input_interface.read_raw().ok_or(IOError {})?;
-        if input != '\x1b' {
-            Err(TuiUnexpectedInputError {
-                expected: '\x1b',
-                recieved: input,
-            })?;
-        }
-        input = self.input_interface.read_raw().ok_or(IOError {})?;
-        if input != '[' {
-            Err(TuiUnexpectedInputError {
-                expected: '\x1b',
-                recieved: input,
-            })?;
-        }
-        let mut y: u16 = 0;
-        let mut x: u16 = 0;
-        loop {
-            input = self.input_interface.read_raw().ok_or(IOError {})?;
-            match input as u8 {
-                0x30..=0x39 => {
-                    let digit = (input as u16) - 0x30;
-                    if u16::MAX / 10 < y {
-                        Err(OverflowError {})?;
-                    }
-                    y *= 10;
-                    if u16::MAX - y < digit {
-                        Err(OverflowError {})?;
-                    }
-                    y += digit;
-                }
-                0x3B => break,
-                _ => Err(TuiUnexpectedInputError {
-                    expected: ';',
-                    recieved: input,
-                })?,
-            }
+    fn restore_colors(&mut self) {
+        if !self.color_overrides.palette.is_empty() {
+            self.reset_palette();
         }
-        loop {
-            input = self.input_interface.read_raw().ok_or(IOError {})?;
-            match input as u8 {
-                0x30..=0x39 => {
-                    let digit = (input as u16) - 0x30;
-                    if u16::MAX / 10 < x {
-                        Err(OverflowError {})?;
-                    }
-                    x *= 10;
-                    if u16::MAX - x < digit {
-                        Err(OverflowError {})?;
-                    }
-                    x += digit;
-                }
-                0x52 => break,
-                _ => Err(TuiUnexpectedInputError {
-                    expected: 'R',
-                    recieved: input,
-                })?,
-            }
+        if self.color_overrides.foreground {
+            _ = self.output_interface.write(b"\x1b]110\x1b\\");
         }
-        return Ok((x, y));
+        if self.color_overrides.background {
+            _ = self.output_interface.write(b"\x1b]111\x1b\\");
+        }
+        if self.color_overrides.cursor {
+            _ = self.output_interface.write(b"\x1b]112\x1b\\");
+        }
+        _ = self.output_interface.flush();
     }
 
     fn send_cursor_code(&mut self) {
@@ -277,38 +329,29 @@ impl TuiTerminal {
         _ = self.output_interface.flush();
     }
 
-    fn send_dec_line_code(&mut self, is_dec_line: bool) {
-        match is_dec_line {
+    fn is_dec_special_graphics(&self, font_settings: &FontSettings) -> bool {
+        let dec_line_mode = match font_settings.dec_line_mode {
+            DecLineMode::Default => self.font_settings.dec_line_mode,
+            dec_line_mode => dec_line_mode,
+        };
+        return font_settings.is_dec_line && dec_line_mode != DecLineMode::Unicode;
+    }
+
+    fn send_dec_line_code(&mut self, font_settings: &FontSettings) {
+        match self.is_dec_special_graphics(font_settings) {
             true => _ = self.output_interface.write(b"\x1b(0"),
             false => _ = self.output_interface.write(b"\x1b(B"),
         }
     }
 
     fn calc_font_settings_code(&self, font_settings: &FontSettings) -> String {
-        let mut code: String = String::from("\x1b[");
-        code += self.get_font_color_code(font_settings.font_color).as_str();
-        code += ";";
-        code += self
-            .get_background_color_code(font_settings.background_color)
-            .as_str();
-        code += ";";
-        code += self.get_bold_code(font_settings.is_bold);
-        code += ";";
-        code += self.get_underlined_code(font_settings.is_underlined);
-        code += ";";
-        code += self.get_italics_code(font_settings.is_italics);
-        code += ";";
-        code += self.get_inverted_code(font_settings.is_inverted);
-        code += ";";
-        code += self.get_blinking_code(font_settings.is_blinking);
-        code += "m";
-        return code;
+        return font_settings.get_sgr_code(&self.font_settings);
     }
 
     fn send_font_settings(&mut self, font_settings: &FontSettings) {
         let code = self.calc_font_settings_code(font_settings);
         _ = self.output_interface.write(code.as_bytes());
-        self.send_dec_line_code(font_settings.is_dec_line);
+        self.send_dec_line_code(font_settings);
         _ = self.output_interface.flush();
         self.clear_end_line();
     }
@@ -316,7 +359,7 @@ impl TuiTerminal {
     fn send_font_settings_passive(&mut self, font_settings: &FontSettings) {
         let code = self.calc_font_settings_code(font_settings);
         _ = self.output_interface.write(code.as_bytes());
-        self.send_dec_line_code(font_settings.is_dec_line);
+        self.send_dec_line_code(font_settings);
         _ = self.output_interface.flush();
     }
 
@@ -349,6 +392,34 @@ impl TuiTerminal {
         self.font_settings.is_blinking = is_blinking;
     }
 
+    pub fn set_strikethrough(&mut self, is_strikethrough: ThreeBool) {
+        self.font_settings.is_strikethrough = is_strikethrough;
+    }
+
+    pub fn set_dim(&mut self, is_dim: ThreeBool) {
+        self.font_settings.is_dim = is_dim;
+    }
+
+    pub fn set_overlined(&mut self, is_overlined: ThreeBool) {
+        self.font_settings.is_overlined = is_overlined;
+    }
+
+    pub fn set_hidden(&mut self, is_hidden: ThreeBool) {
+        self.font_settings.is_hidden = is_hidden;
+    }
+
+    pub fn set_underline_style(&mut self, underline_style: UnderlineStyle) {
+        self.font_settings.underline_style = underline_style;
+    }
+
+    pub fn set_underline_color(&mut self, color: Color) {
+        self.font_settings.underline_color = color;
+    }
+
+    pub fn set_dec_line_mode(&mut self, dec_line_mode: DecLineMode) {
+        self.font_settings.dec_line_mode = dec_line_mode;
+    }
+
     pub fn set_cursor(&mut self, cursor_mode: CursorMode) {
         self.cursor_mode = cursor_mode;
         self.send_cursor_code();
@@ -364,6 +435,7 @@ impl TuiTerminal {
     }
 
     pub fn restore_cursor_position(&mut self) {
+        self.cursor_cell = None;
         _ = self.output_interface.write(b"\x1b8");
         _ = self.output_interface.flush();
     }
@@ -382,16 +454,94 @@ impl TuiTerminal {
         self.send_font_settings_passive(&self.font_settings.clone());
     }
 
+    fn send_hyperlink(&mut self, hyperlink: Option<&Hyperlink>) {
+        let code: String = match hyperlink {
+            Some(hyperlink) => hyperlink.get_open_code(),
+            None => HYPERLINK_CLOSE_CODE.into(),
+        };
+        _ = self.output_interface.write(code.as_bytes());
+    }
+
+    fn send_text(&mut self, text: &str, string_plus: &StringPlus) {
+        let hyperlink = match string_plus.get_hyperlink() {
+            Some(hyperlink) if self.capabilities.hyperlinks && !text.is_empty() => Some(hyperlink),
+            _ => None,
+        };
+        if hyperlink.is_some() {
+            self.send_hyperlink(hyperlink);
+        }
+        let font_settings = self.resolve_font_settings(string_plus);
+        let text: String = match (
+            font_settings.is_dec_line,
+            self.is_dec_special_graphics(&font_settings),
+        ) {
+            (true, true) => text.chars().map(unicode_to_dec).collect(),
+            (true, false) => text.chars().map(dec_to_unicode).collect(),
+            (false, _) => text.to_string(),
+        };
+        _ = self.output_interface.write(text.as_bytes());
+        if hyperlink.is_some() {
+            self.send_hyperlink(None);
+        }
+    }
+
+    fn resolve_font_settings(&self, string_plus: &StringPlus) -> FontSettings {
+        return match string_plus.get_role() {
+            Some(role) => self
+                .theme
+                .get_style(role)
+                .overlay(string_plus.get_font_settings()),
+            None => *string_plus.get_font_settings(),
+        };
+    }
+
+    pub fn get_theme(&self) -> &Theme {
+        return &self.theme;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    pub fn get_capabilities(&self) -> Capabilities {
+        return self.capabilities;
+    }
+
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    fn forget_written_cells(&mut self, string_plus: &StringPlus) {
+        let string: String = string_plus.into();
+        match self.cursor_cell {
+            Some((x, y)) if !string.contains('\n') => {
+                let width = string_plus.width().min(u16::MAX as usize) as u16;
+                self.line_cells.forget(x, y, width);
+                self.cursor_cell = Some((x.saturating_add(width), y));
+            }
+            _ => {
+                self.line_cells.clear();
+                self.cursor_cell = None;
+            }
+        }
+    }
+
     pub fn write<T: Into<StringPlus>>(&mut self, string_plus: T) {
         let string_plus: StringPlus = string_plus.into();
+        self.forget_written_cells(&string_plus);
+        self.write_text(string_plus);
+    }
+
+    fn write_text(&mut self, string_plus: StringPlus) {
         let string: String = (&string_plus).into();
+        let font_settings = self.resolve_font_settings(&string_plus);
         let mut line_number: usize = 0;
         for line in string.split("\n") {
-            self.send_font_settings_passive(string_plus.get_font_settings());
+            self.send_font_settings_passive(&font_settings);
             if line_number != 0 {
                 _ = self.output_interface.write(b"\n");
             }
-            _ = self.output_interface.write(line.as_bytes());
+            self.send_text(line, &string_plus);
             self.send_font_settings_passive(&self.font_settings.clone());
             line_number += 1;
         }
@@ -399,11 +549,14 @@ impl TuiTerminal {
     }
 
     pub fn println<T: Into<StringPlus>>(&mut self, string_plus: T) {
+        self.line_cells.clear();
+        self.cursor_cell = None;
         let string_plus: StringPlus = string_plus.into();
         let string: String = (&string_plus).into();
+        let font_settings = self.resolve_font_settings(&string_plus);
         for line in string.split("\n") {
-            self.send_font_settings(string_plus.get_font_settings());
-            _ = self.output_interface.write(line.as_bytes());
+            self.send_font_settings(&font_settings);
+            self.send_text(line, &string_plus);
             self.send_font_settings(&self.font_settings.clone());
             _ = self.output_interface.write(b"\n");
         }
@@ -411,46 +564,466 @@ impl TuiTerminal {
     }
 
     pub fn print<T: Into<StringPlus>>(&mut self, string_plus: T) {
+        self.line_cells.clear();
+        self.cursor_cell = None;
         let string_plus: StringPlus = string_plus.into();
         let string: String = (&string_plus).into();
+        let font_settings = self.resolve_font_settings(&string_plus);
         let mut line_number: usize = 0;
         for line in string.split("\n") {
-            self.send_font_settings(string_plus.get_font_settings());
+            self.send_font_settings(&font_settings);
             if line_number != 0 {
                 _ = self.output_interface.write(b"\n");
             }
-            _ = self.output_interface.write(line.as_bytes());
+            self.send_text(line, &string_plus);
             self.send_font_settings(&self.font_settings.clone());
             line_number += 1;
         }
         _ = self.output_interface.flush();
     }
 
+    pub fn print_lines(&mut self, x: u16, y: u16, lines: &[Vec<StringPlus>]) {
+        for (row, line) in lines.iter().enumerate() {
+            self.set_cursor_position(x, y.saturating_add(row as u16));
+            for string_plus in line {
+                self.write(string_plus.clone());
+            }
+        }
+    }
+
+    pub(crate) fn get_horizontal_line_cells(x: u16, y: u16, length: u16) -> Vec<((u16, u16), u8)> {
+        return (0..length)
+            .map(|offset| {
+                let mut mask = LINE_LEFT | LINE_RIGHT;
+                if offset == 0 {
+                    mask &= !LINE_LEFT;
+                }
+                if offset == length - 1 {
+                    mask &= !LINE_RIGHT;
+                }
+                ((x.saturating_add(offset), y), mask)
+            })
+            .collect();
+    }
+
+    pub(crate) fn get_vertical_line_cells(x: u16, y: u16, length: u16) -> Vec<((u16, u16), u8)> {
+        return (0..length)
+            .map(|offset| {
+                let mut mask = LINE_UP | LINE_DOWN;
+                if offset == 0 {
+                    mask &= !LINE_UP;
+                }
+                if offset == length - 1 {
+                    mask &= !LINE_DOWN;
+                }
+                ((x, y.saturating_add(offset)), mask)
+            })
+            .collect();
+    }
+
+    pub(crate) fn draw_line_cells(
+        &mut self,
+        cells: Vec<((u16, u16), u8)>,
+        border_style: BorderStyle,
+    ) {
+        if let Ok(size) = self.get_teminal_size() {
+            self.line_cells.set_size(size);
+        }
+        for ((x, y), glyph) in self.line_cells.merge(cells, border_style) {
+            self.set_cursor_position(x, y);
+            self.write_text(glyph);
+            self.cursor_cell = Some((x.saturating_add(1), y));
+        }
+    }
+
+    pub fn draw_horizontal_line(&mut self, x: u16, y: u16, length: u16, border_style: BorderStyle) {
+        let cells = TuiTerminal::get_horizontal_line_cells(x, y, length);
+        self.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_vertical_line(&mut self, x: u16, y: u16, length: u16, border_style: BorderStyle) {
+        let cells = TuiTerminal::get_vertical_line_cells(x, y, length);
+        self.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_box(&mut self, rect: Rect, border_style: BorderStyle) {
+        if rect.width < 2 || rect.height < 2 {
+            return;
+        }
+        let right = rect.right() - 1;
+        let bottom = rect.bottom() - 1;
+        let mut cells = TuiTerminal::get_horizontal_line_cells(rect.x, rect.y, rect.width);
+        cells.append(&mut TuiTerminal::get_horizontal_line_cells(
+            rect.x, bottom, rect.width,
+        ));
+        cells.append(&mut TuiTerminal::get_vertical_line_cells(
+            rect.x,
+            rect.y,
+            rect.height,
+        ));
+        cells.append(&mut TuiTerminal::get_vertical_line_cells(
+            right,
+            rect.y,
+            rect.height,
+        ));
+        self.draw_line_cells(cells, border_style);
+    }
+
+    pub fn draw_titled_box<T: Into<StringPlus>>(
+        &mut self,
+        rect: Rect,
+        border_style: BorderStyle,
+        title: T,
+    ) {
+        self.draw_box(rect, border_style);
+        if rect.width < 5 {
+            return;
+        }
+        let title: StringPlus = title.into();
+        let title = title.truncate(rect.width as usize - 4);
+        self.set_cursor_position(rect.x.saturating_add(1), rect.y);
+        self.write(" ");
+        self.write(title);
+        self.write(" ");
+    }
+
+    pub fn region(&mut self, rect: Rect) -> Region<'_> {
+        return Region::new(self, rect);
+    }
+
+    pub fn draw_canvas(&mut self, canvas: &Canvas, rect: Rect) {
+        self.region(rect).print_lines(1, 1, &canvas.to_lines());
+    }
+
+    pub fn draw_widget<W: Widget>(&mut self, widget: &W, rect: Rect) {
+        self.region(rect).draw_widget(widget);
+    }
+
     pub fn clear_screen(&mut self) {
+        self.line_cells.clear();
+        if let ImageProtocol::Kitty = self.capabilities.images {
+            self.clear_images();
+        }
+        self.images.clear();
         _ = self.output_interface.write(b"\x1b[2J");
         _ = self.output_interface.flush();
     }
 
+    pub fn clear_below_cursor(&mut self) {
+        self.line_cells.clear();
+        _ = self.output_interface.write(b"\x1b[0J");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn clear_above_cursor(&mut self) {
+        self.line_cells.clear();
+        _ = self.output_interface.write(b"\x1b[1J");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn clear_scrollback(&mut self) {
+        _ = self.output_interface.write(b"\x1b[3J");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn erase_characters(&mut self, characters: u16) {
+        self.send_count_code(characters, "X");
+    }
+
     pub fn clear_end_line(&mut self) {
+        self.line_cells.clear();
         _ = self.output_interface.write(b"\x1b[0K");
         _ = self.output_interface.flush();
     }
 
     pub fn clear_beginning_line(&mut self) {
+        self.line_cells.clear();
         _ = self.output_interface.write(b"\x1b[1K");
         _ = self.output_interface.flush();
     }
 
     pub fn clear_line(&mut self) {
+        self.line_cells.clear();
         _ = self.output_interface.write(b"\x1b[2K");
         _ = self.output_interface.flush();
     }
 
+    pub fn get_scroll_region(&self) -> Option<(u16, u16)> {
+        return self.scroll_region;
+    }
+
+    pub fn set_scroll_region(&mut self, top: u16, bottom: u16) -> Result<(), Box<dyn Error>> {
+        let (_, height) = self.get_teminal_size()?;
+        if top < 1 || top >= bottom || bottom > height {
+            return Err(Box::new(ScrollRegionError {
+                top,
+                bottom,
+                height,
+            }));
+        }
+        self.scroll_region = Some((top, bottom));
+        _ = self.output_interface.write(
+            ("\x1b[".to_string() + &top.to_string() + ";" + &bottom.to_string() + "r").as_bytes(),
+        );
+        _ = self.output_interface.flush();
+        return Ok(());
+    }
+
+    pub fn reset_scroll_region(&mut self) {
+        self.scroll_region = None;
+        _ = self.output_interface.write(b"\x1b[r");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn get_horizontal_margins(&self) -> Option<(u16, u16)> {
+        return self.horizontal_margins;
+    }
+
+    pub fn set_horizontal_margins(
+        &mut self,
+        left: u16,
+        right: u16,
+    ) -> Result<(), TuiUnsupportedError> {
+        if !self.capabilities.left_right_margins {
+            return Err(TuiUnsupportedError {
+                feature: "Left/Right Margins".into(),
+            });
+        }
+        self.horizontal_margins = Some((left, right));
+        _ = self.output_interface.write(
+            ("\x1b[?69h\x1b[".to_string() + &left.to_string() + ";" + &right.to_string() + "s")
+                .as_bytes(),
+        );
+        _ = self.output_interface.flush();
+        return Ok(());
+    }
+
+    pub fn reset_horizontal_margins(&mut self) {
+        if self.horizontal_margins.take().is_some() {
+            _ = self.output_interface.write(b"\x1b[s\x1b[?69l");
+            _ = self.output_interface.flush();
+        }
+    }
+
+    fn send_count_code(&mut self, count: u16, final_byte: &str) {
+        self.line_cells.clear();
+        _ = self
+            .output_interface
+            .write(("\x1b[".to_string() + &count.to_string() + final_byte).as_bytes());
+        _ = self.output_interface.flush();
+    }
+
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.send_count_code(lines, "S");
+    }
+
+    pub fn scroll_down(&mut self, lines: u16) {
+        self.send_count_code(lines, "T");
+    }
+
+    pub fn insert_lines(&mut self, lines: u16) {
+        self.send_count_code(lines, "L");
+    }
+
+    pub fn delete_lines(&mut self, lines: u16) {
+        self.send_count_code(lines, "M");
+    }
+
+    pub fn insert_characters(&mut self, characters: u16) {
+        self.send_count_code(characters, "@");
+    }
+
+    pub fn delete_characters(&mut self, characters: u16) {
+        self.send_count_code(characters, "P");
+    }
+
+    fn send_title(&mut self, title: &str, title_kind: TitleKind) {
+        if self.title_stack_depth == 0 {
+            self.push_title();
+        }
+        self.output_interface.set_title(title, title_kind);
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        self.send_title(title, TitleKind::IconNameAndWindowTitle);
+    }
+
+    pub fn set_window_title(&mut self, title: &str) {
+        self.send_title(title, TitleKind::WindowTitle);
+    }
+
+    pub fn set_icon_name(&mut self, icon_name: &str) {
+        self.send_title(icon_name, TitleKind::IconName);
+    }
+
+    pub fn push_title(&mut self) {
+        self.title_stack_depth += 1;
+        self.output_interface.push_title();
+    }
+
+    pub fn pop_title(&mut self) {
+        if self.title_stack_depth > 0 {
+            self.title_stack_depth -= 1;
+            self.output_interface.pop_title();
+        }
+    }
+
+    fn send_passthrough(&mut self, sequence: &str) {
+        self.send_passthrough_body(sequence, "", "");
+    }
+
+    fn send_passthrough_body(&mut self, prefix: &str, body: &str, suffix: &str) {
+        let sequence = self
+            .capabilities
+            .multiplexer
+            .wrap_passthrough(prefix, body, suffix);
+        _ = self.output_interface.write(sequence.as_bytes());
+        _ = self.output_interface.flush();
+    }
+
+    pub fn set_clipboard(&mut self, text: &str) {
+        self.set_clipboard_selection(text, ClipboardSelection::Clipboard);
+    }
+
+    pub fn set_clipboard_selection(&mut self, text: &str, selection: ClipboardSelection) {
+        let prefix: String = "\x1b]52;".to_string() + selection.get_code() + ";";
+        self.send_passthrough_body(&prefix, &base64::encode(text.as_bytes()), "\x07");
+    }
+
+    pub fn get_clipboard(&mut self) -> Result<String, Box<dyn Error>> {
+        return self.get_clipboard_selection(ClipboardSelection::Clipboard);
+    }
+
+    pub fn get_clipboard_selection(
+        &mut self,
+        selection: ClipboardSelection,
+    ) -> Result<String, Box<dyn Error>> {
+        self.send_passthrough(&("\x1b]52;".to_string() + selection.get_code() + ";?\x07"));
+        let reply = self.read_osc_reply("52;")?;
+        let (_, encoded) = reply.split_once(';').ok_or(CError::from(
+            "Invalid Clipboard Reply: ".to_string() + &reply,
+        ))?;
+        let decoded = base64::decode(encoded).ok_or(CError::from(
+            "Invalid Clipboard Reply: ".to_string() + &reply,
+        ))?;
+        return Ok(String::from_utf8(decoded)?);
+    }
+
+    pub fn notify(&mut self, title: &str, body: &str) {
+        self.notification_count = self.notification_count.wrapping_add(1);
+        let protocol = self.capabilities.notifications;
+        if let Some(sequence) = protocol.get_sequence(title, body, self.notification_count) {
+            self.send_passthrough(&sequence);
+        }
+    }
+
+    pub fn bell(&mut self) {
+        _ = self.output_interface.write(b"\x07");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn request_attention(&mut self) {
+        match self.capabilities.terminal_kind {
+            TerminalKind::ITerm2 => self.send_passthrough("\x1b]1337;RequestAttention=yes\x07"),
+            _ => self.bell(),
+        }
+    }
+
+    pub fn get_cell_pixel_size(&mut self) -> Result<(u16, u16), Box<dyn Error>> {
+        if let Some(cell_pixel_size) = self.cell_pixel_size {
+            return Ok(cell_pixel_size);
+        }
+        _ = self.output_interface.write(b"\x1b[16t");
+        _ = self.output_interface.flush();
+        let reply = self.read_reply(|reply| reply.starts_with("[6;") && reply.ends_with('t'))?;
+        let cell_pixel_size = parse_cell_size_reply(&reply).ok_or(CError::from(
+            "Invalid Cell Size Reply: ".to_string() + &reply,
+        ))?;
+        self.cell_pixel_size = Some(cell_pixel_size);
+        return Ok(cell_pixel_size);
+    }
+
+    pub fn draw_image(&mut self, image: &Image, rect: Rect) -> Result<(), Box<dyn Error>> {
+        if rect.is_empty() {
+            return Ok(());
+        }
+        let sequence = match self.capabilities.images {
+            ImageProtocol::Kitty => {
+                encode_kitty(image, rect.width, rect.height, self.image_count + 1)
+            }
+            ImageProtocol::ITerm2 => encode_iterm2(image, rect.width, rect.height),
+            ImageProtocol::Sixel => {
+                let (cell_width, cell_height) = self
+                    .get_cell_pixel_size()
+                    .unwrap_or(DEFAULT_CELL_PIXEL_SIZE);
+                let image = image.resize(
+                    rect.width as u32 * cell_width as u32,
+                    rect.height as u32 * cell_height as u32,
+                );
+                encode_sixel(&image)
+            }
+            ImageProtocol::None => Err(TuiUnsupportedError {
+                feature: "Inline Images".into(),
+            })?,
+        };
+        self.clear_image(rect);
+        self.image_count += 1;
+        self.images.push((rect, self.image_count));
+        for row in rect.top()..rect.bottom() {
+            self.line_cells.forget(rect.x, row, rect.width);
+        }
+        self.save_cursor_position();
+        self.set_cursor_position(rect.x, rect.y);
+        self.send_passthrough(&sequence);
+        self.restore_cursor_position();
+        return Ok(());
+    }
+
+    fn remove_image(&mut self, rect: Rect, id: u32) {
+        if let ImageProtocol::Kitty = self.capabilities.images {
+            let sequence = "\x1b_Ga=d,d=I,q=2,i=".to_string() + &id.to_string() + "\x1b\\";
+            self.send_passthrough(&sequence);
+            return;
+        }
+        self.save_cursor_position();
+        let blank = " ".repeat(rect.width as usize);
+        for row in rect.top()..rect.bottom() {
+            self.set_cursor_position(rect.x, row);
+            self.write(blank.as_str());
+        }
+        self.restore_cursor_position();
+    }
+
+    pub fn clear_image(&mut self, rect: Rect) {
+        let (overlapping, remaining): (Vec<PlacedImage>, Vec<PlacedImage>) = self
+            .images
+            .drain(..)
+            .partition(|(image_rect, _)| !image_rect.intersection(rect).is_empty());
+        self.images = remaining;
+        for (image_rect, id) in overlapping {
+            self.remove_image(image_rect, id);
+        }
+    }
+
+    pub fn clear_images(&mut self) {
+        for (image_rect, id) in std::mem::take(&mut self.images) {
+            self.remove_image(image_rect, id);
+        }
+    }
+
     pub fn get_teminal_size(&self) -> Result<(u16, u16), CError> {
         return self.output_interface.get_size();
     }
 
+    pub fn get_terminal_rect(&self) -> Result<Rect, CError> {
+        let (width, height) = self.get_teminal_size()?;
+        return Ok(Rect::new(1, 1, width, height));
+    }
+
     pub fn get_event(&self) -> TuiEvents {
+        if let Some(event) = self.pending_events.borrow_mut().pop_front() {
+            return event;
+        }
         return self.input_interface.read_parsed();
     }
 
@@ -476,6 +1049,18 @@ impl TuiTerminal {
         _ = self.output_interface.flush();
     }
 
+    pub fn enable_bracketed_paste(&mut self) {
+        self.bracketed_paste = true;
+        _ = self.output_interface.write(b"\x1b[?2004h");
+        _ = self.output_interface.flush();
+    }
+
+    pub fn disable_bracketed_paste(&mut self) {
+        self.bracketed_paste = false;
+        _ = self.output_interface.write(b"\x1b[?2004l");
+        _ = self.output_interface.flush();
+    }
+
     pub fn disable_mouse_events(&mut self) {
         _ = self.output_interface.write("\x1b[?1003l".as_bytes());
         _ = self.output_interface.write("\x1b[?1006l".as_bytes());
@@ -489,6 +1074,17 @@ impl Drop for TuiTerminal {
         self.cursor_mode = CursorMode::Default;
         self.send_cursor_code();
         self.send_font_settings(&FontSettings::default());
+        self.restore_colors();
+        self.reset_horizontal_margins();
+        while self.title_stack_depth > 0 {
+            self.pop_title();
+        }
+        if self.scroll_region.is_some() {
+            self.reset_scroll_region();
+        }
+        if self.bracketed_paste {
+            self.disable_bracketed_paste();
+        }
         self.main_buffer();
         self.disable_mouse_events();
         TerminalManager::reset_terminal_settings(&self.input_interface, &self.terminal_state);
@@ -0,0 +1,22 @@
+use crate::string_plus::StringPlus;
+
+pub trait Widget {
+    fn render(&self, width: u16, height: u16) -> Vec<Vec<StringPlus>>;
+}
+
+pub fn merge_cells(cells: Vec<StringPlus>) -> Vec<StringPlus> {
+    let mut line: Vec<StringPlus> = Vec::new();
+    for cell in cells {
+        match line.last_mut() {
+            Some(previous)
+                if previous.get_font_settings() == cell.get_font_settings()
+                    && previous.get_hyperlink() == cell.get_hyperlink()
+                    && previous.get_role() == cell.get_role() =>
+            {
+                *previous = previous.with_string(previous.to_string() + &cell.to_string());
+            }
+            _ => line.push(cell),
+        }
+    }
+    return line;
+}